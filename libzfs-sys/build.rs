@@ -30,6 +30,7 @@ fn main() {
         .allowlist_type("zfs_prop_t")
         .allowlist_type("zfs_userquota_prop_t")
         .allowlist_type("zpool_prop_t")
+        .allowlist_type("zprop_source_t")
         .allowlist_type("zfs_type_t")
         .bitfield_enum("zfs_type_t")
         .allowlist_type("zfs_error_t")
@@ -38,14 +39,19 @@ fn main() {
         .allowlist_type("zpool_handle_t")
         .allowlist_type("libzfs_handle_t")
         .allowlist_type("zfs_canmount_type_t")
+        .allowlist_type("importargs_t")
+        .allowlist_type("zfeature_info_t")
         .allowlist_function(r#"libzfs_\w*"#)
         .allowlist_function(r#"zpool_\w*"#)
         .allowlist_function(r#"zfs_\w*"#)
         .allowlist_function(r#"zvol_\w*"#)
         .allowlist_var(r#"ZPOOL_CONFIG_\w*"#)
         .allowlist_var(r#"ZPOOL_LOAD_\w*"#)
+        .allowlist_var(r#"ZFS_ONLINE_\w*"#)
         .allowlist_var("ZFS_MAXPROPLEN")
         .allowlist_var("ZPOOL_MAXPROPLEN")
+        .allowlist_var("libzfs_config_ops")
+        .allowlist_var("spa_feature_table")
         .blocklist_item("boolean_t")
         .blocklist_item(r#"\w*nvlist\w*"#)
         .blocklist_item(r#"\w*nvpair\w*"#)
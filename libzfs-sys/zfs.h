@@ -1 +1,3 @@
 #include <libzfs.h>
+#include <libzutil.h>
+#include <zfeature_common.h>
@@ -15,4 +15,21 @@ unsafe fn zfs_version_userland() -> String {
 fn main() {
     let zfs_version = unsafe { zfs_version_userland() };
     println!("cargo:rustc-env=ZFS_VERSION={zfs_version}");
+
+    // Pools have user properties since OpenZFS 2.2
+    println!("cargo:rustc-check-cfg=cfg(zpool_user_properties)");
+    if major_minor(&zfs_version) >= (2, 2) {
+        println!("cargo:rustc-cfg=zpool_user_properties");
+    }
+}
+
+// "zfs-2.1.5-1" is (2, 1)
+fn major_minor(version: &str) -> (u32, u32) {
+    let mut numbers = version
+        .trim_start_matches("zfs-")
+        .split(['.', '-'])
+        .map(|number| number.parse().unwrap_or_default());
+    let major = numbers.next().unwrap_or_default();
+    let minor = numbers.next().unwrap_or_default();
+    (major, minor)
 }
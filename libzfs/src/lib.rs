@@ -22,6 +22,10 @@ use once_cell::sync::Lazy;
 use razor_libnvpair as libnvpair;
 use razor_libzfs_sys as sys;
 
+pub use sys::importargs_t;
+pub use sys::pool_scan_func_t;
+pub use sys::pool_scrub_cmd_t;
+pub use sys::vdev_state_t;
 pub use sys::zfs_canmount_type_t;
 pub use sys::zfs_error;
 pub use sys::zfs_error_t;
@@ -29,8 +33,12 @@ pub use sys::zfs_handle_t;
 pub use sys::zfs_prop_t;
 pub use sys::zfs_type_t;
 pub use sys::zfs_userquota_prop_t;
+pub use sys::zpool_errata_t;
 pub use sys::zpool_handle_t;
 pub use sys::zpool_prop_t;
+pub use sys::zpool_status_t;
+pub use sys::zprop_source_t;
+pub use sys::ZFS_ONLINE_EXPAND;
 
 pub use version::Version;
 
@@ -81,6 +89,11 @@ pub unsafe fn zfs_get_all_props(handle: *mut sys::zfs_handle_t) -> *mut libnvpai
     sys::zfs_get_all_props(handle)
 }
 
+pub unsafe fn zfs_get_user_props(handle: *mut sys::zfs_handle_t) -> *mut libnvpair::nvlist_t {
+    Lazy::force(&LIBZFS_HANDLE);
+    sys::zfs_get_user_props(handle)
+}
+
 pub unsafe fn zfs_prop_get_numeric(
     handle: *mut sys::zfs_handle_t,
     property: sys::zfs_prop_t,
@@ -105,6 +118,70 @@ pub unsafe fn zfs_prop_get_numeric(
     }
 }
 
+pub unsafe fn zfs_prop_get(
+    handle: *mut sys::zfs_handle_t,
+    property: sys::zfs_prop_t,
+    literal: bool,
+) -> Result<String, i32> {
+    Lazy::force(&LIBZFS_HANDLE);
+    let mut buf = vec![0; sys::ZFS_MAXPROPLEN as usize];
+    let src = ptr::null_mut();
+    let statbuf = ptr::null_mut();
+    let statlen = 0;
+    let literal = literal.into();
+    let rc = sys::zfs_prop_get(
+        handle,
+        property,
+        buf.as_mut_ptr(),
+        buf.len(),
+        src,
+        statbuf,
+        statlen,
+        literal,
+    );
+    if rc == 0 {
+        let value = ffi::CStr::from_ptr(buf.as_ptr());
+        Ok(value.to_string_lossy().into_owned())
+    } else {
+        Err(rc)
+    }
+}
+
+/// Like `zfs_prop_get()`, also returning where the value comes from and,
+/// for inherited values, the dataset it is inherited from
+pub unsafe fn zfs_prop_get_with_source(
+    handle: *mut sys::zfs_handle_t,
+    property: sys::zfs_prop_t,
+    literal: bool,
+) -> Result<(String, sys::zprop_source_t, String), i32> {
+    Lazy::force(&LIBZFS_HANDLE);
+    let mut buf = vec![0; sys::ZFS_MAXPROPLEN as usize];
+    let mut src = sys::zprop_source_t::ZPROP_SRC_NONE;
+    let mut statbuf = vec![0; sys::ZFS_MAXPROPLEN as usize];
+    let literal = literal.into();
+    let rc = sys::zfs_prop_get(
+        handle,
+        property,
+        buf.as_mut_ptr(),
+        buf.len(),
+        &mut src,
+        statbuf.as_mut_ptr(),
+        statbuf.len(),
+        literal,
+    );
+    if rc == 0 {
+        let value = ffi::CStr::from_ptr(buf.as_ptr());
+        let from = ffi::CStr::from_ptr(statbuf.as_ptr());
+        Ok((
+            value.to_string_lossy().into_owned(),
+            src,
+            from.to_string_lossy().into_owned(),
+        ))
+    } else {
+        Err(rc)
+    }
+}
+
 pub unsafe fn zfs_prop_get_int(handle: *mut sys::zfs_handle_t, property: sys::zfs_prop_t) -> u64 {
     Lazy::force(&LIBZFS_HANDLE);
     sys::zfs_prop_get_int(handle, property)
@@ -118,6 +195,20 @@ pub unsafe fn zfs_prop_set_list(
     sys::zfs_prop_set_list(dataset_handle, nvl)
 }
 
+pub unsafe fn zfs_name_to_prop(name: *const libc::c_char) -> sys::zfs_prop_t {
+    Lazy::force(&LIBZFS_HANDLE);
+    sys::zfs_name_to_prop(name)
+}
+
+pub unsafe fn zfs_prop_inherit(
+    dataset_handle: *mut sys::zfs_handle_t,
+    name: *const libc::c_char,
+    received: bool,
+) -> libc::c_int {
+    Lazy::force(&LIBZFS_HANDLE);
+    sys::zfs_prop_inherit(dataset_handle, name, received.into())
+}
+
 pub unsafe fn zfs_prop_to_name(property: sys::zfs_prop_t) -> *const libc::c_char {
     Lazy::force(&LIBZFS_HANDLE);
     sys::zfs_prop_to_name(property)
@@ -201,6 +292,31 @@ pub unsafe fn zfs_iter_snapshots(
     sys::zfs_iter_snapshots(handle, simple, callback, data, min_txg, max_txg);
 }
 
+pub unsafe fn zfs_iter_bookmarks(
+    handle: *mut sys::zfs_handle_t,
+    callback: sys::zfs_iter_f,
+    ptr: *mut libc::c_void,
+) {
+    Lazy::force(&LIBZFS_HANDLE);
+    sys::zfs_iter_bookmarks(handle, callback, ptr);
+}
+
+pub unsafe fn zfs_userspace(
+    handle: *mut sys::zfs_handle_t,
+    property: sys::zfs_userquota_prop_t,
+    callback: sys::zfs_userspace_cb_t,
+    ptr: *mut libc::c_void,
+) -> libc::c_int {
+    Lazy::force(&LIBZFS_HANDLE);
+    sys::zfs_userspace(handle, property, callback, ptr)
+}
+
+pub unsafe fn zfs_send_resume_token_to_nvlist(
+    token: *const libc::c_char,
+) -> *mut libnvpair::nvlist_t {
+    sys::zfs_send_resume_token_to_nvlist(LIBZFS_HANDLE.handle(), token)
+}
+
 pub unsafe fn zfs_create(
     path: *const libc::c_char,
     r#type: sys::zfs_type_t,
@@ -209,6 +325,283 @@ pub unsafe fn zfs_create(
     sys::zfs_create(LIBZFS_HANDLE.handle(), path, r#type, props)
 }
 
+pub unsafe fn zpool_open(name: *const libc::c_char) -> *mut sys::zpool_handle_t {
+    sys::zpool_open(LIBZFS_HANDLE.handle(), name)
+}
+
+pub unsafe fn zpool_close(handle: *mut sys::zpool_handle_t) {
+    Lazy::force(&LIBZFS_HANDLE);
+    sys::zpool_close(handle);
+}
+
+pub unsafe fn zpool_get_name(handle: *mut sys::zpool_handle_t) -> *const libc::c_char {
+    Lazy::force(&LIBZFS_HANDLE);
+    sys::zpool_get_name(handle)
+}
+
+pub unsafe fn zpool_get_prop_int(
+    handle: *mut sys::zpool_handle_t,
+    property: sys::zpool_prop_t,
+) -> u64 {
+    Lazy::force(&LIBZFS_HANDLE);
+    sys::zpool_get_prop_int(handle, property, ptr::null_mut())
+}
+
+pub unsafe fn zpool_get_prop(
+    handle: *mut sys::zpool_handle_t,
+    property: sys::zpool_prop_t,
+    literal: bool,
+) -> Result<String, i32> {
+    Lazy::force(&LIBZFS_HANDLE);
+    let mut buf = vec![0; sys::ZPOOL_MAXPROPLEN as usize];
+    let src = ptr::null_mut();
+    let literal = literal.into();
+    let rc = sys::zpool_get_prop(handle, property, buf.as_mut_ptr(), buf.len(), src, literal);
+    if rc == 0 {
+        let value = ffi::CStr::from_ptr(buf.as_ptr());
+        Ok(value.to_string_lossy().into_owned())
+    } else {
+        Err(rc)
+    }
+}
+
+pub unsafe fn zpool_set_prop(
+    handle: *mut sys::zpool_handle_t,
+    name: *const libc::c_char,
+    value: *const libc::c_char,
+) -> libc::c_int {
+    Lazy::force(&LIBZFS_HANDLE);
+    sys::zpool_set_prop(handle, name, value)
+}
+
+pub unsafe fn zpool_prop_get_feature(
+    handle: *mut sys::zpool_handle_t,
+    name: *const libc::c_char,
+) -> Result<String, i32> {
+    Lazy::force(&LIBZFS_HANDLE);
+    let mut buf = vec![0; sys::ZPOOL_MAXPROPLEN as usize];
+    let rc = sys::zpool_prop_get_feature(handle, name, buf.as_mut_ptr(), buf.len());
+    if rc == 0 {
+        let value = ffi::CStr::from_ptr(buf.as_ptr());
+        Ok(value.to_string_lossy().into_owned())
+    } else {
+        Err(rc)
+    }
+}
+
+#[cfg(zpool_user_properties)]
+pub unsafe fn zpool_get_userprop(
+    handle: *mut sys::zpool_handle_t,
+    name: *const libc::c_char,
+) -> Result<String, i32> {
+    Lazy::force(&LIBZFS_HANDLE);
+    let mut buf = vec![0; sys::ZPOOL_MAXPROPLEN as usize];
+    let mut src = mem::MaybeUninit::uninit();
+    let rc = sys::zpool_get_userprop(handle, name, buf.as_mut_ptr(), buf.len(), src.as_mut_ptr());
+    if rc == 0 {
+        let value = ffi::CStr::from_ptr(buf.as_ptr());
+        Ok(value.to_string_lossy().into_owned())
+    } else {
+        Err(rc)
+    }
+}
+
+/// Pools have no user properties before OpenZFS 2.2
+#[cfg(not(zpool_user_properties))]
+pub unsafe fn zpool_get_userprop(
+    _handle: *mut sys::zpool_handle_t,
+    _name: *const libc::c_char,
+) -> Result<String, i32> {
+    Err(libc::ENOTSUP)
+}
+
+/// Names of the features this libzfs knows of, without the `feature@` prefix
+pub fn zpool_feature_names() -> Vec<String> {
+    Lazy::force(&LIBZFS_HANDLE);
+    let table = unsafe { &*ptr::addr_of!(sys::spa_feature_table) };
+    table
+        .iter()
+        .map(|feature| unsafe { ffi::CStr::from_ptr(feature.fi_uname) })
+        .map(|name| name.to_string_lossy().into_owned())
+        .collect()
+}
+
+pub unsafe fn zpool_get_features(handle: *mut sys::zpool_handle_t) -> *mut libnvpair::nvlist_t {
+    Lazy::force(&LIBZFS_HANDLE);
+    sys::zpool_get_features(handle)
+}
+
+pub unsafe fn zpool_name_to_prop(name: *const libc::c_char) -> sys::zpool_prop_t {
+    Lazy::force(&LIBZFS_HANDLE);
+    sys::zpool_name_to_prop(name)
+}
+
+pub unsafe fn zpool_get_config(handle: *mut sys::zpool_handle_t) -> *mut libnvpair::nvlist_t {
+    Lazy::force(&LIBZFS_HANDLE);
+    sys::zpool_get_config(handle, ptr::null_mut())
+}
+
+pub unsafe fn zpool_get_status(
+    handle: *mut sys::zpool_handle_t,
+    msgid: *mut *mut libc::c_char,
+    errata: *mut sys::zpool_errata_t,
+) -> sys::zpool_status_t {
+    Lazy::force(&LIBZFS_HANDLE);
+    sys::zpool_get_status(handle, msgid, errata)
+}
+
+pub unsafe fn zpool_scan(
+    handle: *mut sys::zpool_handle_t,
+    func: sys::pool_scan_func_t,
+    cmd: sys::pool_scrub_cmd_t,
+) -> libc::c_int {
+    Lazy::force(&LIBZFS_HANDLE);
+    sys::zpool_scan(handle, func, cmd)
+}
+
+pub unsafe fn zpool_add(
+    handle: *mut sys::zpool_handle_t,
+    nvroot: *mut libnvpair::nvlist_t,
+) -> libc::c_int {
+    Lazy::force(&LIBZFS_HANDLE);
+    sys::zpool_add(handle, nvroot)
+}
+
+pub unsafe fn zpool_vdev_attach(
+    handle: *mut sys::zpool_handle_t,
+    old_disk: *const libc::c_char,
+    new_disk: *const libc::c_char,
+    nvroot: *mut libnvpair::nvlist_t,
+    replacing: bool,
+) -> libc::c_int {
+    Lazy::force(&LIBZFS_HANDLE);
+    let rebuild = false;
+    sys::zpool_vdev_attach(
+        handle,
+        old_disk,
+        new_disk,
+        nvroot,
+        replacing.into(),
+        rebuild.into(),
+    )
+}
+
+pub unsafe fn zpool_vdev_detach(
+    handle: *mut sys::zpool_handle_t,
+    path: *const libc::c_char,
+) -> libc::c_int {
+    Lazy::force(&LIBZFS_HANDLE);
+    sys::zpool_vdev_detach(handle, path)
+}
+
+pub unsafe fn zpool_vdev_online(
+    handle: *mut sys::zpool_handle_t,
+    path: *const libc::c_char,
+    flags: libc::c_int,
+) -> Result<sys::vdev_state_t, libc::c_int> {
+    Lazy::force(&LIBZFS_HANDLE);
+    let mut state = mem::MaybeUninit::uninit();
+    let rc = sys::zpool_vdev_online(handle, path, flags, state.as_mut_ptr());
+    if rc == 0 {
+        Ok(state.assume_init())
+    } else {
+        Err(rc)
+    }
+}
+
+pub unsafe fn zpool_vdev_offline(
+    handle: *mut sys::zpool_handle_t,
+    path: *const libc::c_char,
+    temporary: bool,
+) -> libc::c_int {
+    Lazy::force(&LIBZFS_HANDLE);
+    sys::zpool_vdev_offline(handle, path, temporary.into())
+}
+
+pub unsafe fn zpool_vdev_remove(
+    handle: *mut sys::zpool_handle_t,
+    path: *const libc::c_char,
+) -> libc::c_int {
+    Lazy::force(&LIBZFS_HANDLE);
+    sys::zpool_vdev_remove(handle, path)
+}
+
+pub unsafe fn zpool_clear(
+    handle: *mut sys::zpool_handle_t,
+    path: *const libc::c_char,
+) -> libc::c_int {
+    Lazy::force(&LIBZFS_HANDLE);
+    sys::zpool_clear(handle, path, ptr::null_mut())
+}
+
+pub unsafe fn zpool_create(
+    name: *const libc::c_char,
+    nvroot: *mut libnvpair::nvlist_t,
+    props: *mut libnvpair::nvlist_t,
+    fsprops: *mut libnvpair::nvlist_t,
+) -> libc::c_int {
+    sys::zpool_create(LIBZFS_HANDLE.handle(), name, nvroot, props, fsprops)
+}
+
+pub unsafe fn zpool_destroy(
+    handle: *mut sys::zpool_handle_t,
+    log_str: *const libc::c_char,
+) -> libc::c_int {
+    Lazy::force(&LIBZFS_HANDLE);
+    sys::zpool_destroy(handle, log_str)
+}
+
+pub unsafe fn zpool_export(
+    handle: *mut sys::zpool_handle_t,
+    force: bool,
+    log_str: *const libc::c_char,
+) -> libc::c_int {
+    Lazy::force(&LIBZFS_HANDLE);
+    sys::zpool_export(handle, force.into(), log_str)
+}
+
+pub unsafe fn zpool_enable_datasets(
+    handle: *mut sys::zpool_handle_t,
+    mntopts: *const libc::c_char,
+    flags: libc::c_int,
+) -> libc::c_int {
+    Lazy::force(&LIBZFS_HANDLE);
+    sys::zpool_enable_datasets(handle, mntopts, flags)
+}
+
+pub unsafe fn zpool_disable_datasets(handle: *mut sys::zpool_handle_t, force: bool) -> libc::c_int {
+    Lazy::force(&LIBZFS_HANDLE);
+    sys::zpool_disable_datasets(handle, force.into())
+}
+
+pub unsafe fn zpool_search_import(args: *mut sys::importargs_t) -> *mut libnvpair::nvlist_t {
+    let handle = LIBZFS_HANDLE.handle() as *mut libc::c_void;
+    sys::zpool_search_import(handle, args, &sys::libzfs_config_ops)
+}
+
+pub unsafe fn zpool_import(
+    config: *mut libnvpair::nvlist_t,
+    newname: *const libc::c_char,
+    altroot: *mut libc::c_char,
+) -> libc::c_int {
+    sys::zpool_import(LIBZFS_HANDLE.handle(), config, newname, altroot)
+}
+
+pub unsafe fn zpool_iter(callback: sys::zpool_iter_f, ptr: *mut libc::c_void) {
+    sys::zpool_iter(LIBZFS_HANDLE.handle(), callback, ptr);
+}
+
+pub unsafe fn zpool_refresh_stats(handle: *mut sys::zpool_handle_t) -> Result<(), libc::c_int> {
+    Lazy::force(&LIBZFS_HANDLE);
+    let mut missing = mem::MaybeUninit::uninit();
+    let rc = sys::zpool_refresh_stats(handle, missing.as_mut_ptr());
+    if rc == 0 {
+        Ok(())
+    } else {
+        Err(rc)
+    }
+}
+
 pub fn zfs_version() -> Version {
     LIBZFS_HANDLE.version().clone()
 }
@@ -30,6 +30,8 @@ pub use sys::pool_trim_func_t;
 
 #[cfg(feature = "wait")]
 pub use sys::zfs_wait_activity_t;
+#[cfg(feature = "wait")]
+pub use sys::zpool_wait_activity_t;
 
 mod lzc;
 
@@ -46,10 +48,10 @@ pub unsafe fn lzc_create(
     name: *const libc::c_char,
     dataset_type: sys::lzc_dataset_type,
     props: *mut libnvpair::nvlist_t,
+    wkeydata: *mut u8,
+    wkeylen: libc::c_uint,
 ) -> libc::c_int {
     Lazy::force(&lzc::LIBZFS_CORE);
-    let wkeydata = ptr::null_mut();
-    let wkeylen = 0;
     sys::lzc_create(name, dataset_type, props, wkeydata, wkeylen)
 }
 
@@ -475,6 +477,27 @@ pub unsafe fn lzc_pool_checkpoint_discard(pool: *const libc::c_char) -> libc::c_
     sys::lzc_pool_checkpoint_discard(pool)
 }
 
+#[cfg(feature = "wait")]
+pub unsafe fn lzc_wait(
+    pool: *const libc::c_char,
+    activity: zpool_wait_activity_t,
+    waited: *mut libnvpair::boolean_t,
+) -> libc::c_int {
+    Lazy::force(&lzc::LIBZFS_CORE);
+    sys::lzc_wait(pool, activity, waited)
+}
+
+#[cfg(feature = "wait")]
+pub unsafe fn lzc_wait_tag(
+    pool: *const libc::c_char,
+    activity: zpool_wait_activity_t,
+    tag: u64,
+    waited: *mut libnvpair::boolean_t,
+) -> libc::c_int {
+    Lazy::force(&lzc::LIBZFS_CORE);
+    sys::lzc_wait_tag(pool, activity, tag, waited)
+}
+
 #[cfg(feature = "wait")]
 pub unsafe fn lzc_wait_fs(
     name: *const libc::c_char,
@@ -15,6 +15,7 @@
 
 use razor_libnvpair as libnvpair;
 
+pub use libnvpair::boolean_t;
 pub use libnvpair::data_type_t;
 pub use libnvpair::NvListError;
 
@@ -62,6 +62,13 @@ impl From<*mut libnvpair::nvlist_t> for NvList {
     }
 }
 
+impl<'a, T> From<&NvListRef<'a, T>> for NvList {
+    fn from(nvl: &NvListRef<'a, T>) -> Self {
+        let nvl = unsafe { libnvpair::fnvlist_dup(nvl.nvl) };
+        Self { nvl }
+    }
+}
+
 impl Drop for NvList {
     fn drop(&mut self) {
         unsafe { libnvpair::fnvlist_free(self.nvl) };
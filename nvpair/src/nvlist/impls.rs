@@ -235,6 +235,14 @@ impl NvList {
         add_string_array_impl(self.nvl, name, v)
     }
 
+    /// Add named nvlist array/slice to this nvlist
+    pub fn add_nvlist_array<L>(&mut self, name: impl AsRef<str>, v: &[L]) -> Result<(), NvListError>
+    where
+        L: AsRef<*mut libnvpair::nvlist_t>,
+    {
+        add_nvlist_array_impl(self.nvl, name, v)
+    }
+
     /// Lookup nvpair by name
     pub fn lookup_nvpair(&self, name: impl AsRef<str>) -> Result<Option<NvPair>, NvListError> {
         let name = cstring(name).map_err(|_| NvListError::InvalidArgument)?;
@@ -300,6 +308,14 @@ impl<'a, T> NvListRef<'a, T> {
         add_string_array_impl(self.nvl, name, v)
     }
 
+    /// Add named nvlist array/slice to this nvlist
+    pub fn add_nvlist_array<L>(&mut self, name: impl AsRef<str>, v: &[L]) -> Result<(), NvListError>
+    where
+        L: AsRef<*mut libnvpair::nvlist_t>,
+    {
+        add_nvlist_array_impl(self.nvl, name, v)
+    }
+
     /// Lookup nvpair by name
     pub fn lookup_nvpair(&self, name: impl AsRef<str>) -> Result<Option<NvPair>, NvListError> {
         let name = cstring(name).map_err(|_| NvListError::InvalidArgument)?;
@@ -370,6 +386,21 @@ where
     unsafe { libnvpair::nvlist_add_string_array(nvl, name.as_ptr(), v.as_ptr(), nelem) }
 }
 
+#[inline]
+fn add_nvlist_array_impl<L>(
+    nvl: *mut libnvpair::nvlist_t,
+    name: impl AsRef<str>,
+    v: &[L],
+) -> Result<(), NvListError>
+where
+    L: AsRef<*mut libnvpair::nvlist_t>,
+{
+    let name = cstring(name)?;
+    let mut v = v.iter().map(|item| *item.as_ref()).collect::<Vec<_>>();
+    let nelem = v.len() as u32;
+    unsafe { libnvpair::nvlist_add_nvlist_array(nvl, name.as_ptr(), v.as_mut_ptr(), nelem) }
+}
+
 #[inline]
 fn cstring(text: impl AsRef<str>) -> Result<ffi::CString, NvListError> {
     ffi::CString::new(text.as_ref()).map_err(|_| NvListError::InvalidArgument)
@@ -48,6 +48,7 @@ pub fn to_value(nvpair: &NvPair) -> Value {
         DATA_TYPE_INT64 => Value::I64(nvpair.int64()),
         DATA_TYPE_UINT64 => Value::U64(nvpair.uint64()),
         DATA_TYPE_STRING => Value::String(nvpair.string().into_owned()),
+        DATA_TYPE_NVLIST => Value::NvList(NvList::from(&nvpair.nvlist())),
 
         DATA_TYPE_BYTE_ARRAY => Value::U8Array(nvpair.byte_array().to_vec()),
 
@@ -64,6 +65,9 @@ pub fn to_value(nvpair: &NvPair) -> Value {
                 .map(|text| text.into_owned())
                 .collect(),
         ),
+        DATA_TYPE_NVLIST_ARRAY => {
+            Value::NvListArray(nvpair.nvlist_array().iter().map(NvList::from).collect())
+        }
 
         DATA_TYPE_BOOLEAN_VALUE => Value::Boolean(nvpair.boolean() == libnvpair::boolean_t::B_TRUE),
         DATA_TYPE_INT8 => Value::I8(nvpair.int8()),
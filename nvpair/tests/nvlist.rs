@@ -27,3 +27,45 @@ fn add_assign() {
     assert_eq!(pair4.r#type(), DATA_TYPE_STRING);
     assert_eq!(None, iter.next());
 }
+
+#[test]
+fn nested_nvlist_value() {
+    let mut inner = NvList::new();
+    inner += ("value", 42u64);
+    let mut nvlist = NvList::new();
+    nvlist.add_nvlist("prop", &inner).unwrap();
+    drop(inner);
+
+    let (name, value) = nvlist.items().next().unwrap();
+    assert_eq!(name, "prop");
+    match value {
+        nvpair::Value::NvList(prop) => {
+            let (name, value) = prop.items().next().unwrap();
+            assert_eq!(name, "value");
+            assert_eq!(value, nvpair::Value::U64(42));
+        }
+        other => panic!("unexpected value {:?}", other),
+    }
+}
+
+#[test]
+fn nvlist_array_value() {
+    let children = (0..3u64)
+        .map(|id| {
+            let mut child = NvList::new();
+            child += ("id", id);
+            child
+        })
+        .collect::<Vec<_>>();
+    let mut nvlist = NvList::new();
+    nvlist.add_nvlist_array("children", &children).unwrap();
+    drop(children);
+
+    let children = nvlist.lookup_nvpair("children").unwrap().unwrap();
+    let ids = children
+        .nvlist_array()
+        .iter()
+        .map(|child| child.lookup_nvpair("id").unwrap().unwrap().uint64())
+        .collect::<Vec<_>>();
+    assert_eq!(ids, [0, 1, 2]);
+}
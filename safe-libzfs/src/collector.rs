@@ -1,41 +1,131 @@
+use std::cmp::Ordering;
 use std::ffi::CString;
-
-use once_cell::sync::Lazy;
-use parking_lot::Mutex;
+use std::vec;
 
 use super::*;
 
-static DATASET_ITERATOR: Lazy<Mutex<DatasetIterator>> = Lazy::new(|| {
-    let iterator = DatasetIterator::new();
-    Mutex::new(iterator)
-});
+fn iter_root() -> Vec<*mut libzfs::zfs_handle_t> {
+    let mut datasets: Vec<*mut libzfs::zfs_handle_t> = vec![];
+    let ptr = &mut datasets as *mut _ as *mut libc::c_void;
+    unsafe { libzfs::zfs_iter_root(Some(zfs_list_cb), ptr) }
+    datasets
+}
 
-struct DatasetIterator {}
+fn iter_filesystem(parent: *mut libzfs::zfs_handle_t) -> Vec<*mut libzfs::zfs_handle_t> {
+    let mut datasets: Vec<*mut libzfs::zfs_handle_t> = vec![];
+    let ptr = &mut datasets as *mut _ as *mut libc::c_void;
+    unsafe { libzfs::zfs_iter_filesystems(parent, Some(zfs_list_cb), ptr) }
+    datasets
+}
+
+fn iter_snapshots(parent: *mut libzfs::zfs_handle_t) -> Vec<*mut libzfs::zfs_handle_t> {
+    let mut datasets: Vec<*mut libzfs::zfs_handle_t> = vec![];
+    let ptr = &mut datasets as *mut _ as *mut libc::c_void;
+    unsafe { libzfs::zfs_iter_snapshots(parent, false, Some(zfs_list_cb), ptr, 0, 0) }
+    datasets
+}
 
-impl DatasetIterator {
-    fn new() -> Self {
-        Self {}
+// Entries of the listed datasets, along with the sort keys to read
+struct Listing {
+    entries: Vec<Entry>,
+    sort: Option<SortBy>,
+}
+
+impl Listing {
+    fn root(sort: Option<SortBy>) -> Vec<Entry> {
+        let mut listing = Self::new(sort);
+        unsafe { libzfs::zfs_iter_root(Some(zfs_entry_cb), listing.as_ptr()) }
+        listing.entries
     }
 
-    fn iter_root(&self) -> Vec<*mut libzfs::zfs_handle_t> {
-        let mut datasets: Vec<*mut libzfs::zfs_handle_t> = vec![];
-        let ptr = &mut datasets as *mut _ as *mut libc::c_void;
-        unsafe { libzfs::zfs_iter_root(Some(zfs_list_cb), ptr) }
-        datasets
+    fn filesystems(parent: &ZfsHandle, sort: Option<SortBy>) -> Vec<Entry> {
+        let mut listing = Self::new(sort);
+        unsafe { libzfs::zfs_iter_filesystems(parent.handle, Some(zfs_entry_cb), listing.as_ptr()) }
+        listing.entries
     }
 
-    fn iter_filesystem(&self, parent: *mut libzfs::zfs_handle_t) -> Vec<*mut libzfs::zfs_handle_t> {
-        let mut datasets: Vec<*mut libzfs::zfs_handle_t> = vec![];
-        let ptr = &mut datasets as *mut _ as *mut libc::c_void;
-        unsafe { libzfs::zfs_iter_filesystems(parent, Some(zfs_list_cb), ptr) }
-        datasets
+    fn snapshots(parent: &ZfsHandle, sort: Option<SortBy>) -> Vec<Entry> {
+        // Simple handles only carry the name and type, which are cheaper to list
+        let simple = !sort.is_some_and(SortBy::needs_stats);
+        let mut listing = Self::new(sort);
+        let ptr = listing.as_ptr();
+        unsafe { libzfs::zfs_iter_snapshots(parent.handle, simple, Some(zfs_entry_cb), ptr, 0, 0) }
+        listing.entries
     }
 
-    fn iter_snapshots(&self, parent: *mut libzfs::zfs_handle_t) -> Vec<*mut libzfs::zfs_handle_t> {
-        let mut datasets: Vec<*mut libzfs::zfs_handle_t> = vec![];
-        let ptr = &mut datasets as *mut _ as *mut libc::c_void;
-        unsafe { libzfs::zfs_iter_snapshots(parent, false, Some(zfs_list_cb), ptr, 0, 0) }
-        datasets
+    fn bookmarks(parent: &ZfsHandle, sort: Option<SortBy>) -> Vec<Entry> {
+        let mut listing = Self::new(sort);
+        unsafe { libzfs::zfs_iter_bookmarks(parent.handle, Some(zfs_entry_cb), listing.as_ptr()) }
+        listing.entries
+    }
+
+    fn new(sort: Option<SortBy>) -> Self {
+        Self {
+            entries: Vec::new(),
+            sort,
+        }
+    }
+
+    fn as_ptr(&mut self) -> *mut libc::c_void {
+        self as *mut _ as *mut libc::c_void
+    }
+}
+
+/// Order of the listed datasets, see `DatasetCollectorBuilder::sort()`
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SortBy {
+    Name,
+    CreateTxg,
+    Creation,
+}
+
+impl SortBy {
+    fn needs_stats(self) -> bool {
+        self != Self::Name
+    }
+
+    // Ties are broken by name by the caller, as `zfs list` does
+    fn compare(self, lhs: &Entry, rhs: &Entry) -> Ordering {
+        match self {
+            Self::Name => lhs.name.cmp(&rhs.name),
+            Self::CreateTxg => lhs.createtxg.cmp(&rhs.createtxg),
+            // creation has a one second resolution
+            Self::Creation => lhs
+                .creation
+                .cmp(&rhs.creation)
+                .then(lhs.createtxg.cmp(&rhs.createtxg)),
+        }
+    }
+}
+
+// Just enough of a dataset to sort it and reopen it later,
+// so that pending datasets do not hold on to libzfs handles
+#[derive(Debug)]
+struct Entry {
+    name: String,
+    r#type: libzfs::zfs_type_t,
+    createtxg: u64,
+    creation: u64,
+}
+
+impl Entry {
+    fn new(dataset: &ZfsHandle, sort: Option<SortBy>) -> Self {
+        let numeric = |property| match sort {
+            Some(by) if by.needs_stats() => dataset.numeric_property(property),
+            _ => 0,
+        };
+        Self {
+            name: dataset.name().into_owned(),
+            r#type: dataset.r#type(),
+            createtxg: numeric(zfs_prop_t::ZFS_PROP_CREATETXG),
+            creation: numeric(zfs_prop_t::ZFS_PROP_CREATION),
+        }
+    }
+
+    fn open(&self) -> Option<ZfsHandle> {
+        // The dataset may have been destroyed since it was listed
+        let name = cstring(&self.name).ok()?;
+        ZfsHandle::new(name).ok()
     }
 }
 
@@ -45,6 +135,9 @@ pub struct DatasetCollectorBuilder {
     datasets: Vec<ZfsHandle>,
     r#type: libzfs::zfs_type_t,
     recursive: bool,
+    max_depth: Option<usize>,
+    patterns: Vec<String>,
+    sort: Option<(SortBy, bool)>,
 }
 
 impl DatasetCollectorBuilder {
@@ -54,6 +147,9 @@ impl DatasetCollectorBuilder {
             datasets: Vec::new(),
             r#type: libzfs::zfs_type_t(0),
             recursive: false,
+            max_depth: None,
+            patterns: Vec::new(),
+            sort: None,
         }
     }
 
@@ -63,6 +159,9 @@ impl DatasetCollectorBuilder {
             datasets: Vec::new(),
             r#type: libzfs::zfs_type_t(0),
             recursive: false,
+            max_depth: None,
+            patterns: Vec::new(),
+            sort: None,
         }
     }
 
@@ -100,6 +199,38 @@ impl DatasetCollectorBuilder {
         self
     }
 
+    /// Descend at most `depth` levels below the starting datasets, as `zfs list -d`
+    #[must_use]
+    pub fn max_depth(mut self, depth: usize) -> Self {
+        self.max_depth = Some(depth);
+        self
+    }
+
+    /// Only yield datasets whose full name matches `pattern`, where `*` matches
+    /// any run of characters (`/` and `@` included) and `?` any single one.
+    /// Can be given several times, matching any of them is enough.
+    #[must_use]
+    pub fn glob(mut self, pattern: impl AsRef<str>) -> Self {
+        self.patterns.push(pattern.as_ref().to_owned());
+        self
+    }
+
+    /// Sort all the listed datasets in ascending order, as `zfs list -s`.
+    /// The name and sort key of every listed dataset are then kept in memory
+    /// until the walk is over, instead of only the siblings along the current path.
+    #[must_use]
+    pub fn sort(mut self, by: SortBy) -> Self {
+        self.sort = Some((by, false));
+        self
+    }
+
+    /// Sort all the listed datasets in descending order, as `zfs list -S`
+    #[must_use]
+    pub fn sort_descending(mut self, by: SortBy) -> Self {
+        self.sort = Some((by, true));
+        self
+    }
+
     fn recursive_children(&mut self, handle: Option<&ZfsHandle>) {
         let childrens = Self::get_children(handle, self.r#type);
 
@@ -111,6 +242,7 @@ impl DatasetCollectorBuilder {
         }
     }
 
+    #[deprecated(note = "walks all the datasets at once, use iter() instead")]
     pub fn get_collection(mut self) -> DatasetCollector {
         let handle = self
             .from_dataset
@@ -133,29 +265,44 @@ impl DatasetCollectorBuilder {
         DatasetCollector::new(self.datasets)
     }
 
+    /// Lazily walk the datasets depth first, the way `zfs list` orders them:
+    /// each dataset is followed by its child filesystems and volumes and their
+    /// descendants, then by its snapshots and bookmarks.
+    /// The walk starts from the `from` dataset, or all the pools' root datasets,
+    /// and goes below them only if `recursive` or `max_depth` is set.
+    /// Filesystems and volumes are listed when no type is asked for.
+    /// Only the siblings along the current path are kept in memory, unless `sort` is set.
+    pub fn iter(self) -> DatasetIter {
+        DatasetIter::new(self)
+    }
+
+    /// Same walk as `iter()`, run on a blocking tokio task that stays at most
+    /// a few datasets ahead of the consumer. Must be called within a tokio runtime.
+    #[cfg(feature = "stream")]
+    pub fn stream(self) -> DatasetStream {
+        DatasetStream::new(self.iter())
+    }
+
     pub fn get_children(
         parent: Option<&ZfsHandle>,
         r#type: libzfs::zfs_type_t,
     ) -> impl Iterator<Item = ZfsHandle> {
         parent
             .map(|parent| parent.handle)
-            .map_or_else(
-                || DATASET_ITERATOR.lock().iter_root(),
-                |parent| {
-                    let mut fs = Vec::new();
-                    let mut snapshots = Vec::new();
-                    if r#type.is_filesystem() || r#type.is_volume() {
-                        fs = DATASET_ITERATOR.lock().iter_filesystem(parent);
-                    }
-
-                    if r#type.is_snapshot() {
-                        snapshots = DATASET_ITERATOR.lock().iter_snapshots(parent);
-                    }
-
-                    fs.append(&mut snapshots);
-                    fs
-                },
-            )
+            .map_or_else(iter_root, |parent| {
+                let mut fs = Vec::new();
+                let mut snapshots = Vec::new();
+                if r#type.is_filesystem() || r#type.is_volume() {
+                    fs = iter_filesystem(parent);
+                }
+
+                if r#type.is_snapshot() {
+                    snapshots = iter_snapshots(parent);
+                }
+
+                fs.append(&mut snapshots);
+                fs
+            })
             .into_iter()
             .map(ZfsHandle::from)
     }
@@ -185,6 +332,255 @@ impl IntoIterator for DatasetCollector {
     }
 }
 
+/// Lazy depth first walk over datasets, see `DatasetCollectorBuilder::iter()`
+#[derive(Debug)]
+pub struct DatasetIter {
+    r#type: libzfs::zfs_type_t,
+    max_depth: Option<usize>,
+    patterns: Vec<String>,
+    sort: Option<(SortBy, bool)>,
+    // Siblings yet to be visited, for each level of the current path
+    stack: Vec<vec::IntoIter<Entry>>,
+    // All the listed datasets in order, once the walk is over when sorting
+    sorted: Option<vec::IntoIter<Entry>>,
+}
+
+impl DatasetIter {
+    fn new(builder: DatasetCollectorBuilder) -> Self {
+        let mut r#type = builder.r#type;
+        if r#type == libzfs::zfs_type_t(0) {
+            r#type = libzfs::zfs_type_t::ZFS_TYPE_FILESYSTEM | libzfs::zfs_type_t::ZFS_TYPE_VOLUME;
+        }
+        let max_depth = match builder.max_depth {
+            Some(depth) => Some(depth),
+            None if builder.recursive => None,
+            None => Some(0),
+        };
+        let mut iter = Self {
+            r#type,
+            max_depth,
+            patterns: builder.patterns,
+            sort: builder.sort,
+            stack: Vec::new(),
+            sorted: None,
+        };
+
+        let start = match builder.from_dataset {
+            Some(name) => cstring(name)
+                .ok()
+                .and_then(|name| ZfsHandle::new(name).ok())
+                .map(|dataset| vec![Entry::new(&dataset, iter.sort_by())])
+                .unwrap_or_default(),
+            None => Listing::root(iter.sort_by()),
+        };
+        iter.stack.push(start.into_iter());
+
+        iter
+    }
+
+    fn sort_by(&self) -> Option<SortBy> {
+        self.sort.map(|(by, _)| by)
+    }
+
+    // Walk the whole tree first, a dataset may sort before any of the ones already seen
+    fn walk_sorted(&mut self, by: SortBy, descending: bool) -> vec::IntoIter<Entry> {
+        let mut entries = Vec::new();
+        while let Some((entry, _)) = self.walk() {
+            entries.push(entry);
+        }
+        entries.sort_by(|lhs, rhs| {
+            let order = by.compare(lhs, rhs);
+            let order = if descending { order.reverse() } else { order };
+            order.then_with(|| lhs.name.cmp(&rhs.name))
+        });
+        entries.into_iter()
+    }
+
+    fn matches(&self, name: &str) -> bool {
+        self.patterns.is_empty()
+            || self
+                .patterns
+                .iter()
+                .any(|pattern| glob_match(pattern, name))
+    }
+
+    fn descends(&self, entry: &Entry, depth: usize) -> bool {
+        let below = libzfs::zfs_type_t::ZFS_TYPE_SNAPSHOT | libzfs::zfs_type_t::ZFS_TYPE_BOOKMARK;
+        self.max_depth.is_none_or(|max_depth| depth < max_depth)
+            && (entry.r#type.is_filesystem()
+                || (entry.r#type.is_volume() && self.r#type.contains(below)))
+    }
+
+    fn children(&self, parent: &ZfsHandle, depth: usize) -> Vec<Entry> {
+        let mut children = Vec::new();
+
+        // Child filesystems are only needed if they are wanted or their own children are
+        let wanted = self.r#type.is_filesystem() || self.r#type.is_volume();
+        let deeper = self.max_depth.is_none_or(|max_depth| depth + 1 < max_depth);
+        if parent.is_filesystem() && (wanted || deeper) {
+            children.append(&mut Listing::filesystems(parent, self.sort_by()));
+        }
+
+        if self.r#type.is_snapshot() {
+            children.append(&mut Listing::snapshots(parent, self.sort_by()));
+        }
+
+        if self.r#type.is_bookmark() {
+            children.append(&mut Listing::bookmarks(parent, self.sort_by()));
+        }
+
+        children
+    }
+
+    // Next wanted dataset of the depth first walk, along with its handle
+    // if it had to be opened to list its children
+    fn walk(&mut self) -> Option<(Entry, Option<ZfsHandle>)> {
+        loop {
+            let depth = self.stack.len().checked_sub(1)?;
+            let entry = match self.stack[depth].next() {
+                Some(entry) => entry,
+                None => {
+                    self.stack.pop();
+                    continue;
+                }
+            };
+
+            let wanted = self.r#type.contains(entry.r#type) && self.matches(&entry.name);
+            let descends = self.descends(&entry, depth);
+            if !wanted && !descends {
+                continue;
+            }
+
+            let mut dataset = None;
+            if descends {
+                let parent = match entry.open() {
+                    Some(parent) => parent,
+                    None => continue,
+                };
+                let children = self.children(&parent, depth);
+                self.stack.push(children.into_iter());
+                dataset = Some(parent);
+            }
+
+            if wanted {
+                return Some((entry, dataset));
+            }
+        }
+    }
+}
+
+impl Iterator for DatasetIter {
+    type Item = ZfsHandle;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let (entry, dataset) = match self.sort {
+                Some((by, descending)) => {
+                    if self.sorted.is_none() {
+                        self.sorted = Some(self.walk_sorted(by, descending));
+                    }
+                    (self.sorted.as_mut()?.next()?, None)
+                }
+                None => self.walk()?,
+            };
+
+            if let Some(dataset) = dataset.or_else(|| entry.open()) {
+                return Some(dataset);
+            }
+        }
+    }
+}
+
+#[cfg(feature = "stream")]
+pub use self::stream::DatasetStream;
+
+#[cfg(feature = "stream")]
+mod stream {
+    use std::pin::Pin;
+    use std::task::{Context, Poll};
+
+    use tokio::sync::mpsc;
+    use tokio::task;
+
+    use super::*;
+
+    // How many datasets the walk may get ahead of the consumer
+    const STREAM_BUFFER: usize = 64;
+
+    /// Asynchronous counterpart of `DatasetIter`, see `DatasetCollectorBuilder::stream()`
+    #[derive(Debug)]
+    pub struct DatasetStream {
+        rx: mpsc::Receiver<ZfsHandle>,
+    }
+
+    impl DatasetStream {
+        pub(super) fn new(iter: DatasetIter) -> Self {
+            let (tx, rx) = mpsc::channel(STREAM_BUFFER);
+            task::spawn_blocking(move || {
+                for dataset in iter {
+                    // The stream was dropped
+                    if tx.blocking_send(dataset).is_err() {
+                        break;
+                    }
+                }
+            });
+            Self { rx }
+        }
+    }
+
+    impl futures_core::Stream for DatasetStream {
+        type Item = ZfsHandle;
+
+        fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+            self.rx.poll_recv(cx)
+        }
+    }
+}
+
+// Shell style matching of `*` and `?`, backtracking to the last `*` on mismatch
+fn glob_match(pattern: &str, name: &str) -> bool {
+    let pattern = pattern.as_bytes();
+    let name = name.as_bytes();
+    let (mut p, mut n) = (0, 0);
+    let mut star = None;
+
+    while n < name.len() {
+        match pattern.get(p) {
+            Some(b'*') => {
+                star = Some((p, n));
+                p += 1;
+            }
+            Some(&c) if c == b'?' || c == name[n] => {
+                p += 1;
+                n += 1;
+            }
+            _ => match star {
+                Some((sp, sn)) => {
+                    star = Some((sp, sn + 1));
+                    p = sp + 1;
+                    n = sn + 1;
+                }
+                None => return false,
+            },
+        }
+    }
+
+    pattern[p..].iter().all(|&c| c == b'*')
+}
+
+#[no_mangle]
+unsafe extern "C" fn zfs_entry_cb(
+    handle: *mut libzfs::zfs_handle_t,
+    ptr: *mut libc::c_void,
+) -> libc::c_int {
+    let listing = &mut *(ptr as *mut Listing);
+    // Closed as soon as its entry is recorded
+    let dataset = ZfsHandle::from(handle);
+    listing.entries.push(Entry::new(&dataset, listing.sort));
+
+    0
+}
+
 // This is probably incorrect
 #[no_mangle]
 unsafe extern "C" fn zfs_list_cb(
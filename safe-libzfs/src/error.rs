@@ -24,6 +24,11 @@ impl ZfsError {
         self.error == zfs_error::EZFS_SUCCESS
     }
 
+    /// The `zfs_error` code
+    pub fn error(&self) -> libzfs::zfs_error_t {
+        self.error
+    }
+
     pub fn from_libzfs_errno() -> Self {
         let code = libzfs_errno();
         let error = code as u32;
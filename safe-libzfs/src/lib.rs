@@ -22,14 +22,36 @@ use std::ffi;
 use razor_libzfs as libzfs;
 use razor_nvpair as nvpair;
 
+pub use libzfs::pool_scan_func_t;
+pub use libzfs::pool_scrub_cmd_t;
+pub use libzfs::vdev_state_t;
 pub use libzfs::zfs_canmount_type_t;
+pub use libzfs::zfs_error;
 pub use libzfs::zfs_prop_t;
+pub use libzfs::zfs_type_t;
+pub use libzfs::zfs_userquota_prop_t;
+pub use libzfs::zpool_errata_t;
+pub use libzfs::zpool_prop_t;
+pub use libzfs::zpool_status_t;
+pub use libzfs::zprop_source_t;
 
 pub use self::collector::DatasetCollectorBuilder;
+pub use self::collector::DatasetIter;
+#[cfg(feature = "stream")]
+pub use self::collector::DatasetStream;
+pub use self::collector::SortBy;
 pub use self::error::ZfsError;
+pub use self::zpool::zpool_create;
+pub use self::zpool::zpool_feature_names;
+pub use self::zpool::zpool_find_import;
+pub use self::zpool::zpool_import;
+pub use self::zpool::zpool_list;
+pub use self::zpool::zpool_name_to_prop;
+pub use self::zpool::ZpoolHandle;
 
 mod collector;
 mod error;
+mod zpool;
 
 #[derive(Debug)]
 pub struct ZfsHandle {
@@ -68,6 +90,10 @@ impl ZfsHandle {
         self.r#type() == libzfs::zfs_type_t::ZFS_TYPE_FILESYSTEM
     }
 
+    pub fn is_snapshot(&self) -> bool {
+        self.r#type() == libzfs::zfs_type_t::ZFS_TYPE_SNAPSHOT
+    }
+
     pub fn zpool_handle(&self) -> *mut libzfs::zpool_handle_t {
         unsafe { libzfs::zfs_get_pool_handle(self.handle) }
     }
@@ -83,6 +109,12 @@ impl ZfsHandle {
         }
     }
 
+    /// User properties by name, each an nvlist with the `value` and the `source` dataset
+    pub fn user_properties(&self) -> nvpair::NvListRef<'_, Self> {
+        let nvl = unsafe { libzfs::zfs_get_user_props(self.handle) };
+        nvpair::NvListRef::from_raw(nvl, self)
+    }
+
     pub fn numeric_property(&self, property: zfs_prop_t) -> u64 {
         unsafe { libzfs::zfs_prop_get_int(self.handle, property) }
     }
@@ -98,6 +130,45 @@ impl ZfsHandle {
         }
     }
 
+    pub fn literal_property(&self, property: zfs_prop_t) -> Option<String> {
+        unsafe { libzfs::zfs_prop_get(self.handle, property, true) }.ok()
+    }
+
+    /// Literal value of `property`, where it comes from and,
+    /// when inherited, the dataset it is inherited from
+    pub fn property_with_source(
+        &self,
+        property: zfs_prop_t,
+    ) -> Option<(String, zprop_source_t, String)> {
+        unsafe { libzfs::zfs_prop_get_with_source(self.handle, property, true) }.ok()
+    }
+
+    /// Clear the local value of property `name` so that it is inherited again,
+    /// or reverts to the received value when `received` is set
+    pub fn inherit_property(
+        &mut self,
+        name: impl AsRef<str>,
+        received: bool,
+    ) -> Result<(), ZfsError> {
+        let name = cstring(name)?;
+        let rc = unsafe { libzfs::zfs_prop_inherit(self.handle, name.as_ptr(), received) };
+        ZfsError::from_rc(rc)
+            .result(())
+            .map(|_| unsafe { libzfs::zfs_refresh_properties(self.handle) })
+    }
+
+    /// Space, object count or quota `property` of each user, group or project
+    /// as (domain, id, value)
+    pub fn userspace(
+        &self,
+        property: zfs_userquota_prop_t,
+    ) -> Result<Vec<(String, u32, u64)>, ZfsError> {
+        let mut entries: Vec<(String, u32, u64)> = vec![];
+        let ptr = &mut entries as *mut _ as *mut libc::c_void;
+        let rc = unsafe { libzfs::zfs_userspace(self.handle, property, Some(userspace_cb), ptr) };
+        ZfsError::from_rc(rc).result(entries)
+    }
+
     pub fn set_properties(&mut self, nvl: impl Into<nvpair::NvList>) -> Result<(), ZfsError> {
         let nvl = nvl.into();
         let rc = unsafe { libzfs::zfs_prop_set_list(self.handle, *nvl) };
@@ -107,6 +178,23 @@ impl ZfsHandle {
     }
 }
 
+unsafe extern "C" fn userspace_cb(
+    ptr: *mut libc::c_void,
+    domain: *const libc::c_char,
+    rid: libc::uid_t,
+    space: u64,
+) -> libc::c_int {
+    let entries = &mut *(ptr as *mut Vec<(String, u32, u64)>);
+    let domain = if domain.is_null() {
+        String::new()
+    } else {
+        ffi::CStr::from_ptr(domain).to_string_lossy().into_owned()
+    };
+    entries.push((domain, rid, space));
+
+    0
+}
+
 impl From<*mut libzfs::zfs_handle_t> for ZfsHandle {
     fn from(handle: *mut libzfs::zfs_handle_t) -> Self {
         Self { handle }
@@ -130,6 +218,15 @@ pub fn zfs_prop_default_numeric(property: zfs_prop_t) -> u64 {
     unsafe { libzfs::zfs_prop_default_numeric(property) }
 }
 
+/// Native dataset property `name`, `None` for user properties
+pub fn zfs_name_to_prop(name: impl AsRef<str>) -> Option<zfs_prop_t> {
+    let name = cstring(name).ok()?;
+    match unsafe { libzfs::zfs_name_to_prop(name.as_ptr()) } {
+        zfs_prop_t::ZPROP_INVAL => None,
+        prop => Some(prop),
+    }
+}
+
 pub fn zfs_prop_to_name(property: zfs_prop_t) -> Cow<'static, str> {
     unsafe {
         let cstr = libzfs::zfs_prop_to_name(property);
@@ -172,6 +269,27 @@ pub fn create_filesystem(
     ZfsError::from_rc(rc).result(())
 }
 
+/// Validate `props` for a new dataset `name` of type `r#type`, see `ZpoolHandle::valid_proplist()`
+pub fn zfs_valid_proplist(
+    name: impl AsRef<str>,
+    r#type: zfs_type_t,
+    props: impl Into<nvpair::NvList>,
+) -> Result<nvpair::NvList, ZfsError> {
+    let name = name.as_ref();
+    let pool = name.split('/').next().unwrap_or(name);
+    ZpoolHandle::new(cstring(pool)?)?.valid_proplist(r#type, props)
+}
+
+pub fn zfs_send_resume_token_to_nvlist(token: impl AsRef<str>) -> Result<nvpair::NvList, ZfsError> {
+    let token = cstring(token)?;
+    let nvl = unsafe { libzfs::zfs_send_resume_token_to_nvlist(token.as_ptr()) };
+    if !nvl.is_null() {
+        Ok(nvl.into())
+    } else {
+        Err(ZfsError::from_libzfs_errno())
+    }
+}
+
 #[inline]
 fn cstring(text: impl AsRef<str>) -> Result<ffi::CString, ffi::NulError> {
     ffi::CString::new(text.as_ref())
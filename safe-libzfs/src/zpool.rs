@@ -0,0 +1,324 @@
+use std::mem;
+use std::ptr;
+
+use super::*;
+
+#[derive(Debug)]
+pub struct ZpoolHandle {
+    handle: *mut libzfs::zpool_handle_t,
+}
+
+unsafe impl ::std::marker::Send for ZpoolHandle {}
+
+impl ZpoolHandle {
+    pub fn new(name: ffi::CString) -> Result<Self, ZfsError> {
+        let handle = unsafe { libzfs::zpool_open(name.as_ptr()) };
+
+        if !handle.is_null() {
+            Ok(Self { handle })
+        } else {
+            Err(ZfsError::from_libzfs_errno())
+        }
+    }
+
+    pub fn name(&self) -> Cow<'_, str> {
+        unsafe {
+            let cstr = libzfs::zpool_get_name(self.handle);
+            ffi::CStr::from_ptr(cstr).to_string_lossy()
+        }
+    }
+
+    pub fn numeric_property(&self, property: zpool_prop_t) -> u64 {
+        unsafe { libzfs::zpool_get_prop_int(self.handle, property) }
+    }
+
+    pub fn literal_property(&self, property: zpool_prop_t) -> Option<String> {
+        unsafe { libzfs::zpool_get_prop(self.handle, property, true) }.ok()
+    }
+
+    pub fn set_property(
+        &self,
+        name: impl AsRef<str>,
+        value: impl AsRef<str>,
+    ) -> Result<(), ZfsError> {
+        let name = cstring(name)?;
+        let value = cstring(value)?;
+        let rc = unsafe { libzfs::zpool_set_prop(self.handle, name.as_ptr(), value.as_ptr()) };
+        ZfsError::from_rc(rc).result(())
+    }
+
+    /// Validate `props` for a new dataset of type `r#type` in this pool the way
+    /// `zfs create` does, converting index values given as strings to numbers
+    pub fn valid_proplist(
+        &self,
+        r#type: zfs_type_t,
+        props: impl Into<nvpair::NvList>,
+    ) -> Result<nvpair::NvList, ZfsError> {
+        let props = props.into();
+        let mut errbuf = [0 as libc::c_char; 1024];
+        let nvl = unsafe {
+            libzfs::zfs_valid_proplist(
+                r#type,
+                *props,
+                false,
+                ptr::null_mut(),
+                self.handle,
+                true,
+                errbuf.as_mut_ptr(),
+            )
+        };
+        if !nvl.is_null() {
+            Ok(nvl.into())
+        } else {
+            Err(ZfsError::from_libzfs_errno())
+        }
+    }
+
+    /// State of the feature `name` (`feature@<feature>`): `disabled`, `enabled` or `active`
+    pub fn feature(&self, name: impl AsRef<str>) -> Result<String, ZfsError> {
+        let name = cstring(name)?;
+        unsafe { libzfs::zpool_prop_get_feature(self.handle, name.as_ptr()) }
+            .map_err(ZfsError::from_rc)
+    }
+
+    /// Value of the user property `name` (`module:property`)
+    pub fn user_property(&self, name: impl AsRef<str>) -> Result<String, ZfsError> {
+        let name = cstring(name)?;
+        unsafe { libzfs::zpool_get_userprop(self.handle, name.as_ptr()) }.map_err(ZfsError::from_rc)
+    }
+
+    /// Reference counts of the enabled features by guid
+    pub fn features(&self) -> Option<nvpair::NvListRef<'_, Self>> {
+        let nvl = unsafe { libzfs::zpool_get_features(self.handle) };
+        if !nvl.is_null() {
+            Some(nvpair::NvListRef::from_raw(nvl, self))
+        } else {
+            None
+        }
+    }
+
+    /// Pool configuration as of the last refresh
+    pub fn config(&self) -> Option<nvpair::NvListRef<'_, Self>> {
+        let nvl = unsafe { libzfs::zpool_get_config(self.handle) };
+        if !nvl.is_null() {
+            Some(nvpair::NvListRef::from_raw(nvl, self))
+        } else {
+            None
+        }
+    }
+
+    /// Health status of the pool as of the last refresh, with the id of the matching
+    /// message in the ZFS message catalog (ZFS-8000-xx) and the errata, if any
+    pub fn status(&self) -> (zpool_status_t, Option<String>, zpool_errata_t) {
+        let mut msgid = ptr::null_mut();
+        let mut errata = zpool_errata_t::ZPOOL_ERRATA_NONE;
+        let status = unsafe { libzfs::zpool_get_status(self.handle, &mut msgid, &mut errata) };
+        let msgid = if !msgid.is_null() {
+            let msgid = unsafe { ffi::CStr::from_ptr(msgid) };
+            Some(msgid.to_string_lossy().into_owned())
+        } else {
+            None
+        };
+        (status, msgid, errata)
+    }
+
+    /// Start, pause or stop a scrub or resilver (`func` POOL_SCAN_NONE stops it)
+    pub fn scan(&self, func: pool_scan_func_t, cmd: pool_scrub_cmd_t) -> Result<(), ZfsError> {
+        let rc = unsafe { libzfs::zpool_scan(self.handle, func, cmd) };
+        ZfsError::from_rc(rc).result(())
+    }
+
+    /// Add the vdevs of `nvroot` to the pool
+    pub fn add(&self, nvroot: &nvpair::NvList) -> Result<(), ZfsError> {
+        let rc = unsafe { libzfs::zpool_add(self.handle, **nvroot) };
+        ZfsError::from_rc(rc).result(())
+    }
+
+    /// Attach the single leaf of `nvroot` named `new` to `old`, mirroring it,
+    /// or replace `old` with it
+    pub fn attach(
+        &self,
+        old: impl AsRef<str>,
+        new: impl AsRef<str>,
+        nvroot: &nvpair::NvList,
+        replacing: bool,
+    ) -> Result<(), ZfsError> {
+        let old = cstring(old)?;
+        let new = cstring(new)?;
+        let rc = unsafe {
+            libzfs::zpool_vdev_attach(self.handle, old.as_ptr(), new.as_ptr(), **nvroot, replacing)
+        };
+        ZfsError::from_rc(rc).result(())
+    }
+
+    pub fn detach(&self, path: impl AsRef<str>) -> Result<(), ZfsError> {
+        let path = cstring(path)?;
+        let rc = unsafe { libzfs::zpool_vdev_detach(self.handle, path.as_ptr()) };
+        ZfsError::from_rc(rc).result(())
+    }
+
+    /// Bring `path` online, growing it to all of its space if `expand`, returning its new state
+    pub fn online(&self, path: impl AsRef<str>, expand: bool) -> Result<vdev_state_t, ZfsError> {
+        let path = cstring(path)?;
+        let flags = if expand {
+            libzfs::ZFS_ONLINE_EXPAND as i32
+        } else {
+            0
+        };
+        unsafe { libzfs::zpool_vdev_online(self.handle, path.as_ptr(), flags) }
+            .map_err(ZfsError::from_rc)
+    }
+
+    /// Take `path` offline, until the next import if `temporary`
+    pub fn offline(&self, path: impl AsRef<str>, temporary: bool) -> Result<(), ZfsError> {
+        let path = cstring(path)?;
+        let rc = unsafe { libzfs::zpool_vdev_offline(self.handle, path.as_ptr(), temporary) };
+        ZfsError::from_rc(rc).result(())
+    }
+
+    pub fn remove(&self, path: impl AsRef<str>) -> Result<(), ZfsError> {
+        let path = cstring(path)?;
+        let rc = unsafe { libzfs::zpool_vdev_remove(self.handle, path.as_ptr()) };
+        ZfsError::from_rc(rc).result(())
+    }
+
+    /// Clear the error counters of `path`, of all the vdevs if `None`
+    pub fn clear(&self, path: Option<&str>) -> Result<(), ZfsError> {
+        let path = path.map(cstring).transpose()?;
+        let rc = unsafe {
+            let path = path.as_ref().map_or(ptr::null(), |path| path.as_ptr());
+            libzfs::zpool_clear(self.handle, path)
+        };
+        ZfsError::from_rc(rc).result(())
+    }
+
+    /// Mount the filesystems of the pool, as `zpool import` does
+    pub fn mount_datasets(&self) -> Result<(), ZfsError> {
+        let rc = unsafe { libzfs::zpool_enable_datasets(self.handle, ptr::null(), 0) };
+        ZfsError::from_rc(rc).result(())
+    }
+
+    /// Unmount the filesystems of the pool and destroy it
+    pub fn destroy(self, force: bool) -> Result<(), ZfsError> {
+        self.unmount_datasets(force)?;
+        let rc = unsafe { libzfs::zpool_destroy(self.handle, ptr::null()) };
+        ZfsError::from_rc(rc).result(())
+    }
+
+    /// Unmount the filesystems of the pool and export it
+    pub fn export(self, force: bool) -> Result<(), ZfsError> {
+        self.unmount_datasets(force)?;
+        let rc = unsafe { libzfs::zpool_export(self.handle, force, ptr::null()) };
+        ZfsError::from_rc(rc).result(())
+    }
+
+    fn unmount_datasets(&self, force: bool) -> Result<(), ZfsError> {
+        let rc = unsafe { libzfs::zpool_disable_datasets(self.handle, force) };
+        ZfsError::from_rc(rc).result(())
+    }
+
+    /// Reload the configuration and the statistics of the pool
+    pub fn refresh_stats(&mut self) -> Result<(), ZfsError> {
+        unsafe { libzfs::zpool_refresh_stats(self.handle) }.map_err(ZfsError::from_rc)
+    }
+}
+
+impl From<*mut libzfs::zpool_handle_t> for ZpoolHandle {
+    fn from(handle: *mut libzfs::zpool_handle_t) -> Self {
+        Self { handle }
+    }
+}
+
+impl Drop for ZpoolHandle {
+    fn drop(&mut self) {
+        unsafe { libzfs::zpool_close(self.handle) };
+    }
+}
+
+/// Native pool property `name`, `None` for feature and user properties
+pub fn zpool_name_to_prop(name: impl AsRef<str>) -> Option<zpool_prop_t> {
+    let name = cstring(name).ok()?;
+    match unsafe { libzfs::zpool_name_to_prop(name.as_ptr()) } {
+        zpool_prop_t::ZPOOL_PROP_INVAL => None,
+        prop => Some(prop),
+    }
+}
+
+/// All the imported pools
+pub fn zpool_list() -> Vec<ZpoolHandle> {
+    let mut pools: Vec<*mut libzfs::zpool_handle_t> = vec![];
+    let ptr = &mut pools as *mut _ as *mut libc::c_void;
+    unsafe { libzfs::zpool_iter(Some(zpool_list_cb), ptr) }
+    pools.into_iter().map(ZpoolHandle::from).collect()
+}
+
+/// Names of the features known to libzfs, without the `feature@` prefix
+pub fn zpool_feature_names() -> Vec<String> {
+    libzfs::zpool_feature_names()
+}
+
+unsafe extern "C" fn zpool_list_cb(
+    handle: *mut libzfs::zpool_handle_t,
+    ptr: *mut libc::c_void,
+) -> libc::c_int {
+    let pools = &mut *(ptr as *mut Vec<*mut libzfs::zpool_handle_t>);
+    pools.push(handle);
+
+    0
+}
+
+/// Create pool `name` with the vdev topology `nvroot`, pool properties `props`
+/// and properties `fsprops` of its root filesystem
+pub fn zpool_create(
+    name: impl AsRef<str>,
+    nvroot: &nvpair::NvList,
+    props: &nvpair::NvList,
+    fsprops: &nvpair::NvList,
+) -> Result<(), ZfsError> {
+    let name = cstring(name)?;
+    let rc = unsafe { libzfs::zpool_create(name.as_ptr(), **nvroot, **props, **fsprops) };
+    ZfsError::from_rc(rc).result(())
+}
+
+/// Search `dirs` (the default device directories if empty) for the exported pool `name`,
+/// returning its configuration
+pub fn zpool_find_import(
+    name: impl AsRef<str>,
+    dirs: &[impl AsRef<str>],
+) -> Result<Option<nvpair::NvList>, ZfsError> {
+    let name = cstring(name)?;
+    let dirs = dirs.iter().map(cstring).collect::<Result<Vec<_>, _>>()?;
+    // dirs needs to live until the search is done
+    let mut paths = dirs
+        .iter()
+        .map(|dir| dir.as_ptr() as *mut libc::c_char)
+        .collect::<Vec<_>>();
+
+    let mut args: libzfs::importargs_t = unsafe { mem::zeroed() };
+    args.poolname = name.as_ptr();
+    if !paths.is_empty() {
+        args.path = paths.as_mut_ptr();
+        args.paths = paths.len() as libc::c_int;
+    }
+
+    let pools = unsafe { libzfs::zpool_search_import(&mut args) };
+    if pools.is_null() {
+        return Err(ZfsError::from_libzfs_errno());
+    }
+    let pools = nvpair::NvList::from(pools);
+    let config = pools
+        .iter()
+        .next()
+        .map(|pool| nvpair::NvList::from(&pool.nvlist()));
+    Ok(config)
+}
+
+/// Import the pool described by `config` (see `zpool_find_import()`), optionally renaming it
+pub fn zpool_import(config: &nvpair::NvList, newname: Option<&str>) -> Result<(), ZfsError> {
+    let newname = newname.map(cstring).transpose()?;
+    let rc = unsafe {
+        let newname = newname.as_ref().map_or(ptr::null(), |name| name.as_ptr());
+        libzfs::zpool_import(**config, newname, ptr::null_mut())
+    };
+    ZfsError::from_rc(rc).result(())
+}
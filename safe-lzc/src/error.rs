@@ -35,6 +35,78 @@ impl fmt::Debug for LzcError {
 
 impl ::std::error::Error for LzcError {}
 
+/// Error of a batched lzc call, along with the per-item errors decoded from its errlist
+///
+#[derive(Clone, PartialEq, Eq)]
+pub struct LzcBatchError {
+    pub code: libc::c_int,
+    pub errors: Vec<(String, LzcError)>,
+}
+
+impl LzcBatchError {
+    pub(crate) fn err(code: libc::c_int, errlist: nvpair::NvList) -> Result<(), Self> {
+        LzcError::err(code).map_err(|_| Self {
+            code,
+            errors: errlist_errors(errlist),
+        })
+    }
+}
+
+impl fmt::Display for LzcBatchError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "LZC error: {} ({})", libc_strerror(self.code), self.code)?;
+        for (name, error) in &self.errors {
+            write!(
+                f,
+                "; {}: {} ({})",
+                name,
+                libc_strerror(error.code),
+                error.code
+            )?;
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Debug for LzcBatchError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("LzcBatchError")
+            .field("code", &self.code)
+            .field("errors", &self.errors)
+            .finish()
+            .and_then(|_| write!(f, " [{}]", libc_strerror(self.code)))
+    }
+}
+
+impl ::std::error::Error for LzcBatchError {}
+
+impl From<LzcError> for LzcBatchError {
+    fn from(e: LzcError) -> Self {
+        Self {
+            code: e.code,
+            errors: Vec::new(),
+        }
+    }
+}
+
+impl From<LzcBatchError> for LzcError {
+    fn from(e: LzcBatchError) -> Self {
+        Self { code: e.code }
+    }
+}
+
+impl From<ffi::NulError> for LzcBatchError {
+    fn from(e: ffi::NulError) -> Self {
+        LzcError::from(e).into()
+    }
+}
+
+impl From<nvpair::NvListError> for LzcBatchError {
+    fn from(e: nvpair::NvListError) -> Self {
+        LzcError::from(e).into()
+    }
+}
+
 impl From<ffi::NulError> for LzcError {
     fn from(_: ffi::NulError) -> Self {
         Self { code: libc::EINVAL }
@@ -51,6 +123,50 @@ impl From<nvpair::NvListError> for LzcError {
     }
 }
 
+// Key lzc uses to report the number of errors that did not fit into errlist
+const N_MORE_ERRORS: &str = "N_MORE_ERRORS";
+
+pub(crate) fn errlist_errors(errlist: nvpair::NvList) -> Vec<(String, LzcError)> {
+    errlist
+        .items()
+        .filter(|(name, _)| name != N_MORE_ERRORS)
+        .filter_map(|(name, value)| match value {
+            nvpair::Value::I32(code) => Some((name, LzcError { code })),
+            _ => None,
+        })
+        .collect()
+}
+
+// initialize and trim report per-vdev errors as int64 values of a nested nvlist,
+// keyed by the vdev guid in decimal. `vdevs` maps the names given by the caller
+// to those guids, so that errors are reported by name as libzfs does.
+pub(crate) fn vdev_errors(
+    errlist: &nvpair::NvList,
+    key: &str,
+    vdevs: &nvpair::NvList,
+) -> Vec<(String, LzcError)> {
+    let name = |guid: String| {
+        vdevs
+            .items()
+            .find(|(_, value)| matches!(value, nvpair::Value::U64(g) if g.to_string() == guid))
+            .map_or(guid, |(name, _)| name)
+    };
+    match errlist.lookup_nvpair(key) {
+        Ok(Some(errors)) => errors
+            .nvlist()
+            .items()
+            .filter_map(|(guid, value)| match value {
+                nvpair::Value::I64(code) => {
+                    let code = code as libc::c_int;
+                    Some((name(guid), LzcError { code }))
+                }
+                _ => None,
+            })
+            .collect(),
+        _ => Vec::new(),
+    }
+}
+
 fn libc_strerror(code: i32) -> Cow<'static, str> {
     unsafe {
         let cstr = libc::strerror(code);
@@ -16,16 +16,30 @@
 //! High level safe wrappers on top of libzfs_core (lzc)
 //!
 
+use std::collections::BTreeMap;
 use std::ffi;
+use std::fs;
+use std::io;
+use std::os::unix::fs::OpenOptionsExt;
 use std::os::unix::io::AsRawFd;
 use std::ptr;
 
 use razor_libzfscore as lzc;
 use razor_nvpair as nvpair;
 
+pub use error::LzcBatchError;
 pub use error::LzcError;
+pub use options::ReceiveOptions;
+pub use options::SendOptions;
+pub use options::VdevAction;
+
+#[cfg(feature = "wait")]
+pub use lzc::zfs_wait_activity_t;
+#[cfg(feature = "wait")]
+pub use lzc::zpool_wait_activity_t;
 
 mod error;
+mod options;
 
 /// Create new ZFS filesystem
 ///
@@ -33,22 +47,128 @@ pub fn create_filesystem(
     name: impl AsRef<str>,
     props: impl nvpair::ToNvList,
 ) -> Result<(), LzcError> {
-    create_dataset(name, lzc::lzc_dataset_type::LZC_DATSET_TYPE_ZFS, props)
+    create_dataset(
+        name,
+        lzc::lzc_dataset_type::LZC_DATSET_TYPE_ZFS,
+        props,
+        None,
+    )
+}
+
+/// Create new encrypted ZFS filesystem, wrapping its master key with `wkey`
+///
+pub fn create_filesystem_with_key(
+    name: impl AsRef<str>,
+    props: impl nvpair::ToNvList,
+    wkey: &[u8],
+) -> Result<(), LzcError> {
+    create_dataset(
+        name,
+        lzc::lzc_dataset_type::LZC_DATSET_TYPE_ZFS,
+        props,
+        Some(wkey),
+    )
 }
 
 /// Create new ZFS volume
 ///
 pub fn create_volume(name: impl AsRef<str>, props: impl nvpair::ToNvList) -> Result<(), LzcError> {
-    create_dataset(name, lzc::lzc_dataset_type::LZC_DATSET_TYPE_ZVOL, props)
+    create_dataset(
+        name,
+        lzc::lzc_dataset_type::LZC_DATSET_TYPE_ZVOL,
+        props,
+        None,
+    )
+}
+
+/// Create new encrypted ZFS volume, wrapping its master key with `wkey`
+///
+pub fn create_volume_with_key(
+    name: impl AsRef<str>,
+    props: impl nvpair::ToNvList,
+    wkey: &[u8],
+) -> Result<(), LzcError> {
+    create_dataset(
+        name,
+        lzc::lzc_dataset_type::LZC_DATSET_TYPE_ZVOL,
+        props,
+        Some(wkey),
+    )
 }
 
 fn create_dataset(
     name: impl AsRef<str>,
     dataset_type: lzc::lzc_dataset_type,
     props: impl nvpair::ToNvList,
+    wkey: Option<&[u8]>,
 ) -> Result<(), LzcError> {
     let cname = cstring(name)?;
-    let code = unsafe { lzc::lzc_create(cname.as_ptr(), dataset_type, props.to_nvlist()) };
+    let (wkeydata, wkeylen) = wkey_parts(wkey)?;
+    let code = unsafe {
+        lzc::lzc_create(
+            cname.as_ptr(),
+            dataset_type,
+            props.to_nvlist(),
+            wkeydata,
+            wkeylen,
+        )
+    };
+    LzcError::err(code)
+}
+
+/// Load the wrapping key `wkey` of the named encrypted dataset.
+/// With `noop` set, only verify the key without loading it.
+///
+pub fn load_key(fsname: impl AsRef<str>, noop: bool, wkey: &[u8]) -> Result<(), LzcError> {
+    let fsname = cstring(fsname)?;
+    let (wkeydata, wkeylen) = wkey_parts(Some(wkey))?;
+    let code = unsafe { lzc::lzc_load_key(fsname.as_ptr(), noop, wkeydata, wkeylen) };
+    LzcError::err(code)
+}
+
+/// Unload the wrapping key of the named encrypted dataset
+///
+pub fn unload_key(fsname: impl AsRef<str>) -> Result<(), LzcError> {
+    let fsname = cstring(fsname)?;
+    let code = unsafe { lzc::lzc_unload_key(fsname.as_ptr()) };
+    LzcError::err(code)
+}
+
+/// Rewrap the master key of the named encryption root with the new wrapping key `wkey`.
+/// `props` may carry the new keyformat, keylocation, pbkdf2salt and pbkdf2iters.
+///
+pub fn change_key(
+    fsname: impl AsRef<str>,
+    props: impl nvpair::ToNvList,
+    wkey: &[u8],
+) -> Result<(), LzcError> {
+    let fsname = cstring(fsname)?;
+    let (wkeydata, wkeylen) = wkey_parts(Some(wkey))?;
+    let code = unsafe {
+        lzc::lzc_change_key(
+            fsname.as_ptr(),
+            DCP_CMD_NEW_KEY,
+            props.to_nvlist(),
+            wkeydata,
+            wkeylen,
+        )
+    };
+    LzcError::err(code)
+}
+
+/// Make the named dataset inherit the wrapping key of its parent encryption root
+///
+pub fn inherit_key(fsname: impl AsRef<str>) -> Result<(), LzcError> {
+    let fsname = cstring(fsname)?;
+    let code = unsafe {
+        lzc::lzc_change_key(
+            fsname.as_ptr(),
+            DCP_CMD_INHERIT,
+            ptr::null_mut(),
+            ptr::null_mut(),
+            0,
+        )
+    };
     LzcError::err(code)
 }
 
@@ -58,26 +178,28 @@ pub fn create_snapshot(
     snapshot: impl AsRef<str>,
     props: impl Into<Option<nvpair::NvList>>,
 ) -> Result<(), LzcError> {
-    create_snapshots([snapshot], props)
+    create_snapshots([snapshot], props)?;
+    Ok(())
 }
 
-/// Create multiple ZFS snapshots, with optional properties
+/// Create multiple ZFS snapshots atomically, with optional properties.
+/// On failure the error lists the snapshots that caused it.
 ///
 pub fn create_snapshots(
     snapshots: impl IntoIterator<Item = impl AsRef<str>>,
     props: impl Into<Option<nvpair::NvList>>,
-) -> Result<(), LzcError> {
+) -> Result<(), LzcBatchError> {
     let props = props.into();
     let mut snaps = nvpair::NvList::new();
     for snapshot in snapshots {
         snaps.add_boolean(snapshot)?;
     }
-    let mut errlist = nvpair::NvList::new();
+    let mut errlist = ptr::null_mut();
     let code = unsafe {
         let props = props.as_deref().map_or_else(ptr::null_mut, |p| *p);
-        lzc::lzc_snapshot(*snaps, props, &mut *errlist)
+        lzc::lzc_snapshot(*snaps, props, &mut errlist)
     };
-    LzcError::err(code)
+    LzcBatchError::err(code, nvpair::NvList::from(errlist))
 }
 
 /// Check named dataset for existence
@@ -98,21 +220,196 @@ pub fn destroy_dataset(name: impl AsRef<str>) -> Result<(), LzcError> {
     LzcError::err(code)
 }
 
+/// Create a clone `fsname` of the snapshot `origin`
+///
+pub fn clone(
+    fsname: impl AsRef<str>,
+    origin: impl AsRef<str>,
+    props: impl nvpair::ToNvList,
+) -> Result<(), LzcError> {
+    let fsname = cstring(fsname)?;
+    let origin = cstring(origin)?;
+    let code = unsafe { lzc::lzc_clone(fsname.as_ptr(), origin.as_ptr(), props.to_nvlist()) };
+    LzcError::err(code)
+}
+
+/// Promote the clone `fsname` so that it no longer depends on its origin snapshot.
+/// When a snapshot of the clone has the same name as one being taken over from the origin,
+/// the error lists that snapshot.
+///
+pub fn promote(fsname: impl AsRef<str>) -> Result<(), LzcBatchError> {
+    let fsname = cstring(fsname)?;
+    let mut snapname = [0; MAX_DATASET_NAME_LEN];
+    let code = unsafe {
+        let len = snapname.len() as libc::c_int;
+        lzc::lzc_promote(fsname.as_ptr(), snapname.as_mut_ptr(), len)
+    };
+    LzcError::err(code).map_err(|error| {
+        let snapname = buf_to_string(&snapname);
+        let errors = if snapname.is_empty() {
+            Vec::new()
+        } else {
+            vec![(snapname, error.clone())]
+        };
+        LzcBatchError {
+            code: error.code,
+            errors,
+        }
+    })
+}
+
+/// Roll back filesystem `fsname` to its most recent snapshot, returning the snapshot name
+///
+pub fn rollback(fsname: impl AsRef<str>) -> Result<String, LzcError> {
+    let fsname = cstring(fsname)?;
+    let mut snapname = [0; MAX_DATASET_NAME_LEN];
+    let code = unsafe {
+        let len = snapname.len() as libc::c_int;
+        lzc::lzc_rollback(fsname.as_ptr(), snapname.as_mut_ptr(), len)
+    };
+    LzcError::err(code)?;
+    Ok(buf_to_string(&snapname))
+}
+
+/// Roll back filesystem `fsname` to `snapname`, which must be its most recent snapshot
+///
+pub fn rollback_to(fsname: impl AsRef<str>, snapname: impl AsRef<str>) -> Result<(), LzcError> {
+    let fsname = cstring(fsname)?;
+    let snapname = cstring(snapname)?;
+    let code = unsafe { lzc::lzc_rollback_to(fsname.as_ptr(), snapname.as_ptr()) };
+    LzcError::err(code)
+}
+
+/// Rename dataset `source` to `target`
+///
+pub fn rename(source: impl AsRef<str>, target: impl AsRef<str>) -> Result<(), LzcError> {
+    let source = cstring(source)?;
+    let target = cstring(target)?;
+    let code = unsafe { lzc::lzc_rename(source.as_ptr(), target.as_ptr()) };
+    LzcError::err(code)
+}
+
+/// Destroy multiple ZFS snapshots atomically, in a single transaction group.
+/// With `defer` set, snapshots that are held or cloned are marked for deferred destruction
+/// instead of failing the whole batch.
+///
+pub fn destroy_snapshots(
+    snapshots: impl IntoIterator<Item = impl AsRef<str>>,
+    defer: bool,
+) -> Result<(), LzcBatchError> {
+    let mut snaps = nvpair::NvList::new();
+    for snapshot in snapshots {
+        snaps.add_boolean(snapshot)?;
+    }
+    let mut errlist = ptr::null_mut();
+    let code = unsafe { lzc::lzc_destroy_snaps(*snaps, defer, &mut errlist) };
+    LzcBatchError::err(code, nvpair::NvList::from(errlist))
+}
+
 /// Create new ZFS bookmark from named snapshot
 ///
 pub fn create_bookmark(
     snapshot: impl AsRef<str>,
     bookmark: impl AsRef<str>,
 ) -> Result<(), LzcError> {
-    let mut bookmarks = nvpair::NvList::new();
-    bookmarks.add_string(bookmark, snapshot)?;
-    let code = unsafe { lzc::lzc_bookmark(*bookmarks, &mut ptr::null_mut()) };
+    create_bookmarks([(snapshot, bookmark)])?;
+    Ok(())
+}
+
+/// Create multiple ZFS bookmarks atomically. `bookmarks` yields (snapshot, bookmark) pairs.
+/// On failure the error lists the bookmarks that caused it.
+///
+pub fn create_bookmarks(
+    bookmarks: impl IntoIterator<Item = (impl AsRef<str>, impl AsRef<str>)>,
+) -> Result<(), LzcBatchError> {
+    let mut nvl = nvpair::NvList::new();
+    for (snapshot, bookmark) in bookmarks {
+        nvl.add_string(bookmark, snapshot)?;
+    }
+    let mut errlist = ptr::null_mut();
+    let code = unsafe { lzc::lzc_bookmark(*nvl, &mut errlist) };
+    LzcBatchError::err(code, nvpair::NvList::from(errlist))
+}
+
+/// Create redaction bookmark `bookname` of `snapshot`, recording the blocks modified
+/// in `redaction_snapshots` (snapshots of clones of `snapshot`) so that a send using
+/// the bookmark leaves them out
+///
+pub fn redact(
+    snapshot: impl AsRef<str>,
+    bookname: impl AsRef<str>,
+    redaction_snapshots: impl IntoIterator<Item = impl AsRef<str>>,
+) -> Result<(), LzcError> {
+    let snapshot = cstring(snapshot)?;
+    let bookname = cstring(bookname)?;
+    let mut snapnv = nvpair::NvList::new();
+    for redaction_snapshot in redaction_snapshots {
+        snapnv.add_boolean(redaction_snapshot)?;
+    }
+    let code = unsafe { lzc::lzc_redact(snapshot.as_ptr(), bookname.as_ptr(), *snapnv) };
     LzcError::err(code)
 }
 
+/// Destroy multiple ZFS bookmarks atomically.
+/// Bookmarks that do not exist are silently ignored.
+///
+pub fn destroy_bookmarks(
+    bookmarks: impl IntoIterator<Item = impl AsRef<str>>,
+) -> Result<(), LzcBatchError> {
+    let mut nvl = nvpair::NvList::new();
+    for bookmark in bookmarks {
+        nvl.add_boolean(bookmark)?;
+    }
+    let mut errlist = ptr::null_mut();
+    let code = unsafe { lzc::lzc_destroy_bookmarks(*nvl, &mut errlist) };
+    LzcBatchError::err(code, nvpair::NvList::from(errlist))
+}
+
+/// Get bookmarks of the named filesystem with the requested properties,
+/// as bookmark (short name) -> property -> value
+///
+pub fn get_bookmarks(
+    filesystem: impl AsRef<str>,
+    props: impl IntoIterator<Item = impl AsRef<str>>,
+) -> Result<BTreeMap<String, BTreeMap<String, nvpair::Value>>, LzcError> {
+    let filesystem = cstring(filesystem)?;
+    let mut nvl = nvpair::NvList::new();
+    for prop in props {
+        nvl.add_boolean(prop)?;
+    }
+    let mut bookmarks = ptr::null_mut();
+    let code = unsafe { lzc::lzc_get_bookmarks(filesystem.as_ptr(), *nvl, &mut bookmarks) };
+    LzcError::err(code)?;
+    let bookmarks = nvpair::NvList::from(bookmarks)
+        .items()
+        .filter_map(|(bookmark, value)| match value {
+            nvpair::Value::NvList(props) => Some((bookmark, prop_values(&props))),
+            _ => None,
+        })
+        .collect();
+    Ok(bookmarks)
+}
+
+/// Get all the properties of the named bookmark (filesystem#bookmark)
+///
+pub fn get_bookmark_props(
+    bookmark: impl AsRef<str>,
+) -> Result<BTreeMap<String, nvpair::Value>, LzcError> {
+    let bookmark = cstring(bookmark)?;
+    let mut props = ptr::null_mut();
+    let code = unsafe { lzc::lzc_get_bookmark_props(bookmark.as_ptr(), &mut props) };
+    LzcError::err(code)?;
+    Ok(prop_values(&nvpair::NvList::from(props)))
+}
+
 /// Send
 ///
-pub fn send<S, F, U>(source: S, from: Option<F>, file: U) -> Result<(), LzcError>
+pub fn send<S, F, U>(
+    source: S,
+    from: Option<F>,
+    options: SendOptions,
+    file: U,
+) -> Result<(), LzcError>
 where
     S: AsRef<str>,
     F: AsRef<str>,
@@ -120,9 +417,7 @@ where
 {
     let source = cstring(source)?;
     let from = from.map(cstring).transpose()?;
-    let flags = lzc::lzc_send_flags::LZC_SEND_FLAG_EMBED_DATA
-        | lzc::lzc_send_flags::LZC_SEND_FLAG_LARGE_BLOCK
-        | lzc::lzc_send_flags::LZC_SEND_FLAG_COMPRESS;
+    let flags = options.flags();
     let code = unsafe {
         let source = source.as_ptr();
         let from = from.map_or(ptr::null(), |from| from.as_ptr());
@@ -137,6 +432,7 @@ where
 pub fn send_resume<S, F, U>(
     source: S,
     from: Option<F>,
+    options: SendOptions,
     file: U,
     resumeobj: u64,
     resumeoff: u64,
@@ -149,9 +445,7 @@ where
     let source = cstring(source)?;
     let from = from.map(cstring).transpose()?;
     let fd = file.as_raw_fd();
-    let flags = lzc::lzc_send_flags::LZC_SEND_FLAG_EMBED_DATA
-        | lzc::lzc_send_flags::LZC_SEND_FLAG_LARGE_BLOCK
-        | lzc::lzc_send_flags::LZC_SEND_FLAG_COMPRESS;
+    let flags = options.flags();
     let code = unsafe {
         let source = source.as_ptr();
         let from = from.map_or(ptr::null(), |from| from.as_ptr());
@@ -160,13 +454,156 @@ where
     LzcError::err(code)
 }
 
+/// Estimate the size of the stream `send()` would produce with the same arguments
+///
+pub fn send_space<S, F>(
+    snapname: S,
+    from: Option<F>,
+    options: &SendOptions,
+) -> Result<u64, LzcError>
+where
+    S: AsRef<str>,
+    F: AsRef<str>,
+{
+    let snapname = cstring(snapname)?;
+    let from = from.map(cstring).transpose()?;
+    let flags = options.flags();
+    let mut space = 0;
+    let code = unsafe {
+        let snapname = snapname.as_ptr();
+        let from = from.map_or(ptr::null(), |from| from.as_ptr());
+        lzc::lzc_send_space(snapname, from, flags, &mut space)
+    };
+    LzcError::err(code)?;
+    Ok(space)
+}
+
+/// Estimate the size of the remainder of a resumed (and possibly redacted) stream
+/// of which `resume_bytes` have already been sent
+///
+#[allow(clippy::too_many_arguments)]
+pub fn send_space_resume_redacted<S, F, R, U>(
+    snapname: S,
+    from: Option<F>,
+    options: &SendOptions,
+    resumeobj: u64,
+    resumeoff: u64,
+    resume_bytes: u64,
+    redactbook: Option<R>,
+    file: U,
+) -> Result<u64, LzcError>
+where
+    S: AsRef<str>,
+    F: AsRef<str>,
+    R: AsRef<str>,
+    U: AsRawFd,
+{
+    let snapname = cstring(snapname)?;
+    let from = from.map(cstring).transpose()?;
+    let redactbook = redactbook.map(cstring).transpose()?;
+    let flags = options.flags();
+    let fd = file.as_raw_fd();
+    let mut space = 0;
+    let code = unsafe {
+        let snapname = snapname.as_ptr();
+        let from = from.map_or(ptr::null(), |from| from.as_ptr());
+        let redactbook = redactbook.map_or(ptr::null(), |redactbook| redactbook.as_ptr());
+        lzc::lzc_send_space_resume_redacted(
+            snapname,
+            from,
+            flags,
+            resumeobj,
+            resumeoff,
+            resume_bytes,
+            redactbook,
+            fd,
+            &mut space,
+        )
+    };
+    LzcError::err(code)?;
+    Ok(space)
+}
+
+/// Space that would be freed by destroying the snapshots from `firstsnap` to `lastsnap` inclusive
+///
+pub fn snaprange_space(
+    firstsnap: impl AsRef<str>,
+    lastsnap: impl AsRef<str>,
+) -> Result<u64, LzcError> {
+    let firstsnap = cstring(firstsnap)?;
+    let lastsnap = cstring(lastsnap)?;
+    let mut space = 0;
+    let code =
+        unsafe { lzc::lzc_snaprange_space(firstsnap.as_ptr(), lastsnap.as_ptr(), &mut space) };
+    LzcError::err(code)?;
+    Ok(space)
+}
+
+/// Send `source` redacted by the redaction bookmark `redactbook` (see `redact()`)
+///
+pub fn send_redacted<S, F, R, U>(
+    source: S,
+    from: Option<F>,
+    redactbook: R,
+    options: SendOptions,
+    file: U,
+) -> Result<(), LzcError>
+where
+    S: AsRef<str>,
+    F: AsRef<str>,
+    R: AsRef<str>,
+    U: AsRawFd,
+{
+    let source = cstring(source)?;
+    let from = from.map(cstring).transpose()?;
+    let redactbook = cstring(redactbook)?;
+    let flags = options.flags();
+    let code = unsafe {
+        let source = source.as_ptr();
+        let from = from.map_or(ptr::null(), |from| from.as_ptr());
+        let fd = file.as_raw_fd();
+        lzc::lzc_send_redacted(source, from, fd, flags, redactbook.as_ptr())
+    };
+    LzcError::err(code)
+}
+
+/// Send redacted with resume
+///
+pub fn send_resume_redacted<S, F, R, U>(
+    source: S,
+    from: Option<F>,
+    redactbook: R,
+    options: SendOptions,
+    file: U,
+    resumeobj: u64,
+    resumeoff: u64,
+) -> Result<(), LzcError>
+where
+    S: AsRef<str>,
+    F: AsRef<str>,
+    R: AsRef<str>,
+    U: AsRawFd,
+{
+    let source = cstring(source)?;
+    let from = from.map(cstring).transpose()?;
+    let redactbook = cstring(redactbook)?;
+    let fd = file.as_raw_fd();
+    let flags = options.flags();
+    let code = unsafe {
+        let source = source.as_ptr();
+        let from = from.map_or(ptr::null(), |from| from.as_ptr());
+        let redactbook = redactbook.as_ptr();
+        lzc::lzc_send_resume_redacted(source, from, fd, flags, resumeobj, resumeoff, redactbook)
+    };
+    LzcError::err(code)
+}
+
 /// Receive
 ///
 pub fn receive<S, O, U>(
     snapname: S,
     origin: Option<O>,
-    force: bool,
-    raw: bool,
+    options: ReceiveOptions,
     file: U,
 ) -> Result<(), LzcError>
 where
@@ -178,35 +615,128 @@ where
     let origin = origin.map(cstring).transpose()?;
     let props = nvpair::NvList::new();
     let fd = file.as_raw_fd();
+    let force = options.is_force();
+    let raw = options.is_raw();
     let code = unsafe {
         let snapname = snapname.as_ptr();
         let origin = origin.map_or(ptr::null(), |origin| origin.as_ptr());
-        lzc::lzc_receive(snapname, *props, origin, force, raw, fd)
+        if options.is_resumable() {
+            lzc::lzc_receive_resumable(snapname, *props, origin, force, raw, fd)
+        } else {
+            lzc::lzc_receive(snapname, *props, origin, force, raw, fd)
+        }
     };
     LzcError::err(code)
 }
 
-/// Receive with resume
+/// Receive with resume. An interrupted receive keeps its partial state on the target,
+/// and the target's `receive_resume_token` property tells the sender where to resume.
 ///
-pub fn receive_resumable(
-    snapname: impl AsRef<str>,
-    origin: impl AsRef<str>,
+pub fn receive_resumable<S, O, U>(
+    snapname: S,
+    origin: Option<O>,
     force: bool,
     raw: bool,
-    file: impl AsRawFd,
-) -> Result<(), LzcError> {
+    file: U,
+) -> Result<(), LzcError>
+where
+    S: AsRef<str>,
+    O: AsRef<str>,
+    U: AsRawFd,
+{
     let snapname = cstring(snapname)?;
     let props = nvpair::NvList::new();
-    let origin = cstring(origin)?;
+    let origin = origin.map(cstring).transpose()?;
     let fd = file.as_raw_fd();
     let code = unsafe {
         let snapname = snapname.as_ptr();
-        let origin = origin.as_ptr();
+        let origin = origin.map_or(ptr::null(), |origin| origin.as_ptr());
         lzc::lzc_receive_resumable(snapname, *props, origin, force, raw, fd)
     };
     LzcError::err(code)
 }
 
+/// Place user holds on snapshots. `holds` yields (snapshot, tag) pairs.
+/// Snapshots that no longer exist are skipped and reported back with their error.
+///
+pub fn hold(
+    holds: impl IntoIterator<Item = (impl AsRef<str>, impl AsRef<str>)>,
+) -> Result<Vec<(String, LzcError)>, LzcBatchError> {
+    hold_impl(holds, -1)
+}
+
+/// Place user holds on snapshots which are released automatically when `cleanup` is closed
+/// (at the latest, when the process exits). See `cleanup_fd()`.
+///
+pub fn hold_with_cleanup(
+    holds: impl IntoIterator<Item = (impl AsRef<str>, impl AsRef<str>)>,
+    cleanup: impl AsRawFd,
+) -> Result<Vec<(String, LzcError)>, LzcBatchError> {
+    hold_impl(holds, cleanup.as_raw_fd())
+}
+
+fn hold_impl(
+    holds: impl IntoIterator<Item = (impl AsRef<str>, impl AsRef<str>)>,
+    cleanup_fd: libc::c_int,
+) -> Result<Vec<(String, LzcError)>, LzcBatchError> {
+    let mut nvl = nvpair::NvList::new();
+    for (snapshot, tag) in holds {
+        nvl.add_string(snapshot, tag)?;
+    }
+    let mut errlist = ptr::null_mut();
+    let code = unsafe { lzc::lzc_hold(*nvl, cleanup_fd, &mut errlist) };
+    batch_result(code, nvpair::NvList::from(errlist))
+}
+
+/// Release user holds. `holds` yields (snapshot, tag) pairs.
+/// Snapshots that no longer exist are skipped and reported back with their error.
+///
+pub fn release(
+    holds: impl IntoIterator<Item = (impl AsRef<str>, impl AsRef<str>)>,
+) -> Result<Vec<(String, LzcError)>, LzcBatchError> {
+    let mut tags = BTreeMap::<String, nvpair::NvList>::new();
+    for (snapshot, tag) in holds {
+        tags.entry(snapshot.as_ref().to_string())
+            .or_default()
+            .add_boolean(tag)?;
+    }
+    let mut nvl = nvpair::NvList::new();
+    for (snapshot, tags) in tags {
+        nvl.add_nvlist(snapshot, tags)?;
+    }
+    let mut errlist = ptr::null_mut();
+    let code = unsafe { lzc::lzc_release(*nvl, &mut errlist) };
+    batch_result(code, nvpair::NvList::from(errlist))
+}
+
+/// Get user holds of the named snapshot as tag -> creation time (seconds since epoch)
+///
+pub fn get_holds(snapshot: impl AsRef<str>) -> Result<BTreeMap<String, u64>, LzcError> {
+    let snapshot = cstring(snapshot)?;
+    let mut holds = ptr::null_mut();
+    let code = unsafe { lzc::lzc_get_holds(snapshot.as_ptr(), &mut holds) };
+    LzcError::err(code)?;
+    let holds = nvpair::NvList::from(holds)
+        .items()
+        .filter_map(|(tag, value)| match value {
+            nvpair::Value::U64(created) => Some((tag, created)),
+            _ => None,
+        })
+        .collect();
+    Ok(holds)
+}
+
+/// Open a new cleanup file descriptor for `hold_with_cleanup()`.
+/// All the holds placed with it are released when it is closed.
+///
+pub fn cleanup_fd() -> io::Result<fs::File> {
+    fs::OpenOptions::new()
+        .read(true)
+        .write(true)
+        .custom_flags(libc::O_EXCL | libc::O_CLOEXEC)
+        .open(ZFS_DEV)
+}
+
 /// Sync named zpool
 ///
 pub fn sync_pool(pool: impl AsRef<str>, force: bool) -> Result<(), LzcError> {
@@ -217,6 +747,208 @@ pub fn sync_pool(pool: impl AsRef<str>, force: bool) -> Result<(), LzcError> {
     LzcError::err(rc)
 }
 
+/// Checkpoint the current state of named zpool, so that it can be rewound to later.
+/// A pool has at most one checkpoint.
+///
+pub fn checkpoint(pool: impl AsRef<str>) -> Result<(), LzcError> {
+    let pool = cstring(pool)?;
+    let code = unsafe { lzc::lzc_pool_checkpoint(pool.as_ptr()) };
+    LzcError::err(code)
+}
+
+/// Discard the checkpoint of named zpool, releasing the space it holds
+///
+pub fn discard_checkpoint(pool: impl AsRef<str>) -> Result<(), LzcError> {
+    let pool = cstring(pool)?;
+    let code = unsafe { lzc::lzc_pool_checkpoint_discard(pool.as_ptr()) };
+    LzcError::err(code)
+}
+
+/// Wait until no `activity` is in progress in named zpool.
+/// Returns whether it actually had to wait.
+///
+#[cfg(feature = "wait")]
+pub fn wait(pool: impl AsRef<str>, activity: zpool_wait_activity_t) -> Result<bool, LzcError> {
+    let pool = cstring(pool)?;
+    let mut waited = nvpair::boolean_t::B_FALSE;
+    let code = unsafe { lzc::lzc_wait(pool.as_ptr(), activity, &mut waited) };
+    LzcError::err(code)?;
+    Ok(bool::from(&waited))
+}
+
+/// Wait until `activity` identified by `tag` (e.g. the guid of the vdev being initialized
+/// or replaced) is no longer in progress in named zpool.
+/// Returns whether it actually had to wait.
+///
+#[cfg(feature = "wait")]
+pub fn wait_tag(
+    pool: impl AsRef<str>,
+    activity: zpool_wait_activity_t,
+    tag: u64,
+) -> Result<bool, LzcError> {
+    let pool = cstring(pool)?;
+    let mut waited = nvpair::boolean_t::B_FALSE;
+    let code = unsafe { lzc::lzc_wait_tag(pool.as_ptr(), activity, tag, &mut waited) };
+    LzcError::err(code)?;
+    Ok(bool::from(&waited))
+}
+
+/// Wait until no `activity` is in progress in named filesystem.
+/// Returns whether it actually had to wait.
+///
+#[cfg(feature = "wait")]
+pub fn wait_fs(name: impl AsRef<str>, activity: zfs_wait_activity_t) -> Result<bool, LzcError> {
+    let name = cstring(name)?;
+    let mut waited = nvpair::boolean_t::B_FALSE;
+    let code = unsafe { lzc::lzc_wait_fs(name.as_ptr(), activity, &mut waited) };
+    LzcError::err(code)?;
+    Ok(bool::from(&waited))
+}
+
+/// Start, cancel or suspend initializing (writing a pattern over all the unallocated space)
+/// vdevs of named zpool. `vdevs` yields (name, guid) pairs, the guid selects the vdev
+/// and the name is used to report its error.
+/// On failure the error lists the vdevs that caused it.
+///
+pub fn initialize(
+    pool: impl AsRef<str>,
+    vdevs: impl IntoIterator<Item = (impl AsRef<str>, u64)>,
+    action: VdevAction,
+) -> Result<(), LzcBatchError> {
+    let pool = cstring(pool)?;
+    let vdevs = vdev_guids(vdevs)?;
+    let mut errlist = ptr::null_mut();
+    let code = unsafe {
+        lzc::lzc_initialize(
+            pool.as_ptr(),
+            action.initialize_func(),
+            *vdevs,
+            &mut errlist,
+        )
+    };
+    vdev_result(
+        code,
+        nvpair::NvList::from(errlist),
+        ZPOOL_INITIALIZE_VDEVS,
+        &vdevs,
+    )
+}
+
+/// Start, cancel or suspend TRIM of the unallocated space of vdevs of named zpool.
+/// `vdevs` yields (name, guid) pairs as in `initialize()`. `rate` limits the TRIM
+/// in bytes per second per vdev (0 for no limit), `secure` requests a secure TRIM.
+/// On failure the error lists the vdevs that caused it.
+///
+pub fn trim(
+    pool: impl AsRef<str>,
+    vdevs: impl IntoIterator<Item = (impl AsRef<str>, u64)>,
+    rate: u64,
+    secure: bool,
+    action: VdevAction,
+) -> Result<(), LzcBatchError> {
+    let pool = cstring(pool)?;
+    let vdevs = vdev_guids(vdevs)?;
+    let mut errlist = ptr::null_mut();
+    let code = unsafe {
+        lzc::lzc_trim(
+            pool.as_ptr(),
+            action.trim_func(),
+            rate,
+            secure,
+            *vdevs,
+            &mut errlist,
+        )
+    };
+    vdev_result(
+        code,
+        nvpair::NvList::from(errlist),
+        ZPOOL_TRIM_VDEVS,
+        &vdevs,
+    )
+}
+
+const ZFS_DEV: &str = "/dev/zfs";
+
+// ZFS_MAX_DATASET_NAME_LEN
+const MAX_DATASET_NAME_LEN: usize = 256;
+
+const ZPROP_VALUE: &str = "value";
+
+// Keys of the per-vdev errors in the errlist of initialize and trim
+const ZPOOL_INITIALIZE_VDEVS: &str = "initialize_vdevs";
+const ZPOOL_TRIM_VDEVS: &str = "trim_vdevs";
+
+// dcp_cmd_t
+const DCP_CMD_NEW_KEY: u64 = 2;
+const DCP_CMD_INHERIT: u64 = 3;
+
+fn buf_to_string(buf: &[libc::c_char]) -> String {
+    unsafe { ffi::CStr::from_ptr(buf.as_ptr()) }
+        .to_string_lossy()
+        .into_owned()
+}
+
+// Properties come back as name -> { "value": value, ... }
+fn prop_values(props: &nvpair::NvList) -> BTreeMap<String, nvpair::Value> {
+    props
+        .items()
+        .filter_map(|(name, value)| match value {
+            nvpair::Value::NvList(prop) => prop
+                .lookup_nvpair(ZPROP_VALUE)
+                .ok()
+                .flatten()
+                .map(|value| (name, value.value())),
+            _ => None,
+        })
+        .collect()
+}
+
+// Some lzc calls report skipped items in errlist even when they succeed
+fn batch_result(
+    code: libc::c_int,
+    errlist: nvpair::NvList,
+) -> Result<Vec<(String, LzcError)>, LzcBatchError> {
+    if code == 0 {
+        Ok(error::errlist_errors(errlist))
+    } else {
+        LzcBatchError::err(code, errlist).map(|_| Vec::new())
+    }
+}
+
+fn vdev_guids(
+    vdevs: impl IntoIterator<Item = (impl AsRef<str>, u64)>,
+) -> Result<nvpair::NvList, nvpair::NvListError> {
+    let mut nvl = nvpair::NvList::new();
+    for (name, guid) in vdevs {
+        nvl.add_uint64(name, guid)?;
+    }
+    Ok(nvl)
+}
+
+fn vdev_result(
+    code: libc::c_int,
+    errlist: nvpair::NvList,
+    key: &str,
+    vdevs: &nvpair::NvList,
+) -> Result<(), LzcBatchError> {
+    LzcError::err(code).map_err(|error| LzcBatchError {
+        code: error.code,
+        errors: error::vdev_errors(&errlist, key, vdevs),
+    })
+}
+
+// lzc takes a non-const pointer to the wrapping key, but only copies it
+fn wkey_parts(wkey: Option<&[u8]>) -> Result<(*mut u8, libc::c_uint), LzcError> {
+    match wkey {
+        Some(wkey) => {
+            let len =
+                libc::c_uint::try_from(wkey.len()).map_err(|_| LzcError { code: libc::EINVAL })?;
+            Ok((wkey.as_ptr() as *mut u8, len))
+        }
+        None => Ok((ptr::null_mut(), 0)),
+    }
+}
+
 #[inline]
 fn cstring(text: impl AsRef<str>) -> Result<ffi::CString, ffi::NulError> {
     ffi::CString::new(text.as_ref())
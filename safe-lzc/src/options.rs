@@ -0,0 +1,206 @@
+use super::*;
+
+/// Send stream options (lzc send flags)
+///
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct SendOptions {
+    embed_data: bool,
+    large_block: bool,
+    compressed: bool,
+    raw: bool,
+    saved: bool,
+}
+
+impl SendOptions {
+    /// Default options: embedded data, large blocks and compressed records
+    ///
+    pub fn new() -> Self {
+        Self {
+            embed_data: true,
+            large_block: true,
+            compressed: true,
+            raw: false,
+            saved: false,
+        }
+    }
+
+    /// Plain options with every flag off, suitable for the oldest receivers
+    ///
+    pub fn plain() -> Self {
+        Self {
+            embed_data: false,
+            large_block: false,
+            compressed: false,
+            raw: false,
+            saved: false,
+        }
+    }
+
+    /// Send WRITE_EMBEDDED records
+    ///
+    #[must_use]
+    pub fn embed_data(mut self, yes: bool) -> Self {
+        self.embed_data = yes;
+        self
+    }
+
+    /// Send records larger than 128k
+    ///
+    #[must_use]
+    pub fn large_block(mut self, yes: bool) -> Self {
+        self.large_block = yes;
+        self
+    }
+
+    /// Send compressed records as they are stored on disk
+    ///
+    #[must_use]
+    pub fn compressed(mut self, yes: bool) -> Self {
+        self.compressed = yes;
+        self
+    }
+
+    /// Send encrypted datasets as they are stored on disk, without decrypting them
+    ///
+    #[must_use]
+    pub fn raw(mut self, yes: bool) -> Self {
+        self.raw = yes;
+        self
+    }
+
+    /// Send the partially received state of an interrupted resumable receive
+    ///
+    #[must_use]
+    pub fn saved(mut self, yes: bool) -> Self {
+        self.saved = yes;
+        self
+    }
+
+    pub fn is_embed_data(&self) -> bool {
+        self.embed_data
+    }
+
+    pub fn is_large_block(&self) -> bool {
+        self.large_block
+    }
+
+    pub fn is_compressed(&self) -> bool {
+        self.compressed
+    }
+
+    pub fn is_raw(&self) -> bool {
+        self.raw
+    }
+
+    pub fn is_saved(&self) -> bool {
+        self.saved
+    }
+
+    pub(crate) fn flags(&self) -> lzc::lzc_send_flags {
+        let mut flags = lzc::lzc_send_flags(0);
+        if self.embed_data {
+            flags |= lzc::lzc_send_flags::LZC_SEND_FLAG_EMBED_DATA;
+        }
+        if self.large_block {
+            flags |= lzc::lzc_send_flags::LZC_SEND_FLAG_LARGE_BLOCK;
+        }
+        if self.compressed {
+            flags |= lzc::lzc_send_flags::LZC_SEND_FLAG_COMPRESS;
+        }
+        if self.raw {
+            flags |= lzc::lzc_send_flags::LZC_SEND_FLAG_RAW;
+        }
+        if self.saved {
+            flags |= lzc::lzc_send_flags::LZC_SEND_FLAG_SAVED;
+        }
+        flags
+    }
+}
+
+impl Default for SendOptions {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Receive options
+///
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct ReceiveOptions {
+    raw: bool,
+    force: bool,
+    resumable: bool,
+}
+
+impl ReceiveOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The stream is raw (sent with `SendOptions::raw()`)
+    ///
+    #[must_use]
+    pub fn raw(mut self, yes: bool) -> Self {
+        self.raw = yes;
+        self
+    }
+
+    /// Roll the target back to its most recent snapshot before receiving
+    ///
+    #[must_use]
+    pub fn force(mut self, yes: bool) -> Self {
+        self.force = yes;
+        self
+    }
+
+    /// Keep the partially received state if the receive is interrupted,
+    /// so that it can be resumed later
+    ///
+    #[must_use]
+    pub fn resumable(mut self, yes: bool) -> Self {
+        self.resumable = yes;
+        self
+    }
+
+    pub fn is_raw(&self) -> bool {
+        self.raw
+    }
+
+    pub fn is_force(&self) -> bool {
+        self.force
+    }
+
+    pub fn is_resumable(&self) -> bool {
+        self.resumable
+    }
+}
+
+/// Action of `initialize()` and `trim()` on the selected vdevs
+///
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum VdevAction {
+    /// Start, or resume when suspended
+    Start,
+    /// Stop and forget the progress made so far
+    Cancel,
+    /// Stop, keeping the progress so that a later `Start` resumes from there
+    Suspend,
+}
+
+impl VdevAction {
+    pub(crate) fn initialize_func(self) -> lzc::pool_initialize_func_t {
+        match self {
+            Self::Start => lzc::pool_initialize_func_t::POOL_INITIALIZE_START,
+            Self::Cancel => lzc::pool_initialize_func_t::POOL_INITIALIZE_CANCEL,
+            Self::Suspend => lzc::pool_initialize_func_t::POOL_INITIALIZE_SUSPEND,
+        }
+    }
+
+    pub(crate) fn trim_func(self) -> lzc::pool_trim_func_t {
+        match self {
+            Self::Start => lzc::pool_trim_func_t::POOL_TRIM_START,
+            Self::Cancel => lzc::pool_trim_func_t::POOL_TRIM_CANCEL,
+            Self::Suspend => lzc::pool_trim_func_t::POOL_TRIM_SUSPEND,
+        }
+    }
+}
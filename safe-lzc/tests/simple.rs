@@ -1,3 +1,5 @@
+use std::os::unix::io::AsRawFd;
+
 use razor_nvpair as nvpair;
 use razor_safe_lzc as lzc;
 use razor_test::TestNamespace;
@@ -101,3 +103,62 @@ fn snapshot_with_properties() {
     lzc::destroy_dataset(&name).expect("destroy filesystem");
     assert!(!lzc::dataset_exists(&name));
 }
+
+#[test]
+fn hold_release() {
+    let namespace = TestNamespace::unique();
+    let name = namespace.unique_name();
+    let mut props = nvpair::NvList::new();
+    props += ("razor-test:clean", "yes");
+    lzc::create_filesystem(&name, props).expect("create filesystem");
+    let snap = format!("{name}@hold_release");
+    let missing = format!("{name}@missing");
+    lzc::create_snapshot(&snap, None).expect("create snapshot");
+    let skipped = lzc::hold([(&snap, "razor-test"), (&missing, "razor-test")]).expect("hold");
+    assert_eq!(skipped, [(missing, lzc::LzcError { code: libc::ENOENT })]);
+    let holds = lzc::get_holds(&snap).expect("get holds");
+    assert!(holds.contains_key("razor-test"));
+    let e = lzc::destroy_dataset(&snap).unwrap_err();
+    assert_eq!(e.code, libc::EBUSY);
+    let skipped = lzc::release([(&snap, "razor-test")]).expect("release");
+    assert!(skipped.is_empty());
+    assert!(lzc::get_holds(&snap).expect("get holds").is_empty());
+    lzc::destroy_dataset(&snap).expect("destroy snapshot");
+    lzc::destroy_dataset(&name).expect("destroy filesystem");
+}
+
+#[test]
+fn hold_with_cleanup() {
+    let namespace = TestNamespace::unique();
+    let name = namespace.unique_name();
+    let mut props = nvpair::NvList::new();
+    props += ("razor-test:clean", "yes");
+    lzc::create_filesystem(&name, props).expect("create filesystem");
+    let snap = format!("{name}@hold_with_cleanup");
+    lzc::create_snapshot(&snap, None).expect("create snapshot");
+    let cleanup = lzc::cleanup_fd().expect("cleanup fd");
+    lzc::hold_with_cleanup([(&snap, "razor-test")], cleanup.as_raw_fd()).expect("hold");
+    assert!(lzc::get_holds(&snap)
+        .expect("get holds")
+        .contains_key("razor-test"));
+    drop(cleanup);
+    assert!(lzc::get_holds(&snap).expect("get holds").is_empty());
+    lzc::destroy_dataset(&snap).expect("destroy snapshot");
+    lzc::destroy_dataset(&name).expect("destroy filesystem");
+}
+
+#[test]
+fn snapshots_batch_error() {
+    let namespace = TestNamespace::unique();
+    let name = namespace.unique_name();
+    let mut props = nvpair::NvList::new();
+    props += ("razor-test:clean", "yes");
+    lzc::create_filesystem(&name, props).expect("create filesystem");
+    let snap = format!("{name}@batch");
+    let missing = format!("{name}/missing@batch");
+    let e = lzc::create_snapshots([&snap, &missing], None).unwrap_err();
+    assert_eq!(e.code, libc::ENOENT);
+    assert_eq!(e.errors, [(missing, lzc::LzcError { code: libc::ENOENT })]);
+    assert!(!lzc::dataset_exists(&snap));
+    lzc::destroy_dataset(&name).expect("destroy filesystem");
+}
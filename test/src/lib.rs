@@ -56,6 +56,10 @@ impl TestNamespace {
         sleep(self.delay);
     }
 
+    pub fn pool(&self) -> &str {
+        &self.pool
+    }
+
     pub fn unique_name(&self) -> String {
         format!("{}/{}", self.namespace.name(), nanoid!(8, ALPHANUMERIC))
     }
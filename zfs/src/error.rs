@@ -12,10 +12,30 @@ pub enum DatasetError {
     InvalidProperty(#[from] zfs::property::InvalidProperty),
     #[error(transparent)]
     NvListError(#[from] NvListError),
+    #[error("Invalid encryption key ({0})")]
+    InvalidKey(String),
+    #[error("Invalid resume token ({0})")]
+    InvalidResumeToken(String),
     #[error("Snapshot name must contain @ ({0})")]
     InvalidSnapshotName(String),
+    #[error("Snapshot {0} is not the most recent one")]
+    NotLatestSnapshot(String),
+    #[error("Snapshot {0} conflicts with a snapshot of the origin")]
+    PromoteConflict(String),
+    #[error("Vdev {0} not found in pool")]
+    UnknownVdev(String),
+    #[error("Invalid vdev ({0})")]
+    InvalidVdev(String),
+    #[error("Pool {0} already exists")]
+    PoolExists(String),
+    #[error("Pool {0} not found")]
+    PoolNotFound(String),
+    #[error("Pool {0} is busy")]
+    PoolBusy(String),
     #[error(transparent)]
     CoreErr(#[from] libzfs::ZfsError),
+    #[error(transparent)]
+    BatchError(#[from] lzc::LzcBatchError),
     #[error("unknown builder error, error code: ({0})")]
     Unknown(i32),
 }
@@ -28,6 +48,10 @@ impl DatasetError {
     pub fn invalid_snapshot_name(name: impl AsRef<str>) -> Self {
         Self::InvalidSnapshotName(name.as_ref().to_string())
     }
+
+    pub fn invalid_resume_token(reason: impl AsRef<str>) -> Self {
+        Self::InvalidResumeToken(reason.as_ref().to_string())
+    }
 }
 
 impl From<io::Error> for DatasetError {
@@ -22,15 +22,54 @@ pub use nvpair::NvListError;
 
 pub use error::DatasetError;
 pub use zfs::Bookmark;
+pub use zfs::Dataset;
+pub use zfs::DatasetIter;
+#[cfg(feature = "stream")]
+pub use zfs::DatasetStream;
 pub use zfs::Filesystem;
 pub use zfs::FilesystemBuilder;
+pub use zfs::KeyMaterial;
+pub use zfs::KeySource;
+pub use zfs::ReceiveOptions;
+pub use zfs::ResumeToken;
+pub use zfs::SendOptions;
 pub use zfs::Snapshot;
 pub use zfs::SnapshotBuilder;
+pub use zfs::SnapshotSet;
+pub use zfs::SortBy;
+pub use zfs::SpaceEntry;
+pub use zfs::UserquotaProp;
 pub use zfs::Volume;
 pub use zfs::VolumeBuilder;
 pub use zfs::Zfs;
+pub use zpool::Checkpoint;
+pub use zpool::CheckpointState;
+pub use zpool::CheckpointStatus;
+pub use zpool::PoolStatus;
+pub use zpool::Progress;
+pub use zpool::ProgressState;
+pub use zpool::ScanFunction;
+pub use zpool::ScanState;
+pub use zpool::ScanStatus;
+pub use zpool::ScrubAction;
+pub use zpool::Vdev;
+pub use zpool::VdevAction;
+pub use zpool::VdevProgress;
+pub use zpool::VdevSpec;
+pub use zpool::VdevStatus;
+pub use zpool::Zpool;
+pub use zpool::ZpoolBuilder;
+pub use zpool::ZpoolPropSetter;
+
+#[cfg(feature = "wait")]
+pub use wait::zfs_wait_activity_t;
+#[cfg(feature = "wait")]
+pub use wait::zpool_wait_activity_t;
 
 mod error;
+#[cfg(feature = "wait")]
+mod wait;
 pub mod zfs;
+pub mod zpool;
 
 pub type Result<T, E = DatasetError> = std::result::Result<T, E>;
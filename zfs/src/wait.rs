@@ -0,0 +1,22 @@
+use std::future::Future;
+use std::panic;
+
+use super::*;
+
+pub use lzc::zfs_wait_activity_t;
+pub use lzc::zpool_wait_activity_t;
+
+/// Run a blocking wait on the blocking thread pool of the tokio runtime
+pub(crate) fn spawn_blocking<F>(wait: F) -> impl Future<Output = Result<bool>>
+where
+    F: FnOnce() -> Result<bool, lzc::LzcError> + Send + 'static,
+{
+    let task = tokio::task::spawn_blocking(wait);
+    async move {
+        match task.await {
+            Ok(waited) => Ok(waited?),
+            Err(err) if err.is_panic() => panic::resume_unwind(err.into_panic()),
+            Err(_) => Err(DatasetError::Unknown(libc::ECANCELED)),
+        }
+    }
+}
@@ -1,20 +1,37 @@
+use std::fs;
 use std::os::unix::io::AsRawFd;
 
+pub use crypto::KeyMaterial;
+pub use crypto::KeySource;
 pub use dataset::Bookmark;
+pub use dataset::Dataset;
 pub use dataset::Filesystem;
 pub use dataset::FilesystemBuilder;
 pub use dataset::Snapshot;
 pub use dataset::SnapshotBuilder;
+pub use dataset::SnapshotSet;
 pub use dataset::Volume;
 pub use dataset::VolumeBuilder;
+pub use libzfs::DatasetIter;
+#[cfg(feature = "stream")]
+pub use libzfs::DatasetStream;
+pub use libzfs::SortBy;
+pub use lzc::ReceiveOptions;
+pub use lzc::SendOptions;
 pub use property::Properties;
+pub use resume::ResumeToken;
+pub use userspace::SpaceEntry;
+pub use userspace::UserquotaProp;
 
 use super::*;
 
 #[cfg(feature = "cmd")]
 mod cmd;
+mod crypto;
 mod dataset;
 pub mod property;
+mod resume;
+mod userspace;
 
 #[derive(Debug)]
 pub struct Zfs {}
@@ -45,6 +62,23 @@ impl Zfs {
         Ok(())
     }
 
+    /// Destroy all the `snapshots` atomically in one transaction group.
+    /// With `defer` set, held or cloned snapshots are destroyed once released.
+    pub fn destroy_snapshots(
+        snapshots: impl IntoIterator<Item = impl AsRef<str>>,
+        defer: bool,
+    ) -> Result<()> {
+        lzc::destroy_snapshots(snapshots, defer)?;
+        Ok(())
+    }
+
+    /// Open a cleanup file descriptor for `Snapshot::hold_with_cleanup()`.
+    /// Holds placed with it are released when it is closed or the process exits.
+    pub fn cleanup_fd() -> Result<fs::File> {
+        let file = lzc::cleanup_fd()?;
+        Ok(file)
+    }
+
     pub fn dataset_exists(dataset: impl AsRef<str>) -> bool {
         lzc::dataset_exists(dataset)
     }
@@ -73,24 +107,88 @@ impl Zfs {
         Bookmark::get(name)
     }
 
-    pub fn send<S, F, U>(source: S, from: Option<F>, file: U) -> Result<()>
+    /// Rename dataset `from` to `to`
+    pub fn rename(from: impl AsRef<str>, to: impl AsRef<str>) -> Result<()> {
+        lzc::rename(from, to)?;
+        Ok(())
+    }
+
+    /// Destroy all the `bookmarks` atomically
+    pub fn destroy_bookmarks(bookmarks: impl IntoIterator<Item = impl AsRef<str>>) -> Result<()> {
+        lzc::destroy_bookmarks(bookmarks)?;
+        Ok(())
+    }
+
+    pub fn send<S, F, U>(source: S, from: Option<F>, options: SendOptions, file: U) -> Result<()>
+    where
+        S: AsRef<str>,
+        F: AsRef<str>,
+        U: AsRawFd,
+    {
+        lzc::send(source, from, options, file)?;
+        Ok(())
+    }
+
+    /// Send `source` leaving out the blocks recorded in the redaction bookmark `redactbook`
+    pub fn send_redacted<S, F, R, U>(
+        source: S,
+        from: Option<F>,
+        redactbook: R,
+        options: SendOptions,
+        file: U,
+    ) -> Result<()>
     where
         S: AsRef<str>,
         F: AsRef<str>,
+        R: AsRef<str>,
         U: AsRawFd,
     {
-        lzc::send(source, from, file)?;
+        lzc::send_redacted(source, from, redactbook, options, file)?;
         Ok(())
     }
 
-    pub fn receive<S, O, U>(snapname: S, origin: Option<O>, force: bool, file: U) -> Result<()>
+    /// Resume an interrupted send from where the receiving side's `token` says it stopped
+    pub fn send_resume(token: &ResumeToken, file: impl AsRawFd) -> Result<()> {
+        let from = token.from()?;
+        lzc::send_resume(
+            token.toname(),
+            from,
+            token.options(),
+            file,
+            token.object(),
+            token.offset(),
+        )?;
+        Ok(())
+    }
+
+    /// Estimated size of the stream `send()` would produce with the same arguments
+    pub fn send_space<S, F>(source: S, from: Option<F>, options: SendOptions) -> Result<u64>
+    where
+        S: AsRef<str>,
+        F: AsRef<str>,
+    {
+        let size = lzc::send_space(source, from, &options)?;
+        Ok(size)
+    }
+
+    /// Space that would be freed by destroying the snapshots from `first` to `last` inclusive
+    pub fn snaprange_space(first: impl AsRef<str>, last: impl AsRef<str>) -> Result<u64> {
+        let space = lzc::snaprange_space(first, last)?;
+        Ok(space)
+    }
+
+    pub fn receive<S, O, U>(
+        snapname: S,
+        origin: Option<O>,
+        options: ReceiveOptions,
+        file: U,
+    ) -> Result<()>
     where
         S: AsRef<str>,
         O: AsRef<str>,
         U: AsRawFd,
     {
-        let raw = false;
-        lzc::receive(snapname, origin, force, raw, file)?;
+        lzc::receive(snapname, origin, options, file)?;
         Ok(())
     }
 }
@@ -6,13 +6,14 @@ use super::*;
 const ZFS: &str = "/usr/sbin/zfs";
 
 impl Zfs {
-    pub fn send_cmd<S, F>(source: S, from: Option<F>) -> Result<Child>
+    pub fn send_cmd<S, F>(source: S, from: Option<F>, options: SendOptions) -> Result<Child>
     where
         S: AsRef<str>,
         F: AsRef<str>,
     {
         let mut send = Command::new(ZFS);
         send.arg("send");
+        send.args(send_args(options));
         if let Some(from) = from {
             send.args(&["-i", from.as_ref()]);
         }
@@ -23,7 +24,20 @@ impl Zfs {
         Ok(child)
     }
 
-    pub fn receive_cmd<S, O>(snapname: S, origin: Option<O>, force: bool) -> Result<Child>
+    pub fn send_resume_cmd(token: &ResumeToken) -> Result<Child> {
+        let mut send = Command::new(ZFS);
+        send.args(["send", "-t", token.as_str()])
+            .stdout(Stdio::piped())
+            .kill_on_drop(true);
+        let child = send.spawn()?;
+        Ok(child)
+    }
+
+    pub fn receive_cmd<S, O>(
+        snapname: S,
+        origin: Option<O>,
+        options: ReceiveOptions,
+    ) -> Result<Child>
     where
         S: AsRef<str>,
         O: AsRef<str>,
@@ -33,9 +47,12 @@ impl Zfs {
             .arg(snapname.as_ref())
             .stdin(Stdio::piped())
             .kill_on_drop(true);
-        if force {
+        if options.is_force() {
             recv.arg("-F");
         }
+        if options.is_resumable() {
+            recv.arg("-s");
+        }
         if let Some(origin) = origin {
             recv.args(["-o", &format!("origin={}", origin.as_ref())]);
         }
@@ -43,3 +60,16 @@ impl Zfs {
         Ok(child)
     }
 }
+
+fn send_args(options: SendOptions) -> Vec<&'static str> {
+    [
+        (options.is_embed_data(), "-e"),
+        (options.is_large_block(), "-L"),
+        (options.is_compressed(), "-c"),
+        (options.is_raw(), "-w"),
+        (options.is_saved(), "-S"),
+    ]
+    .into_iter()
+    .filter_map(|(yes, arg)| yes.then_some(arg))
+    .collect()
+}
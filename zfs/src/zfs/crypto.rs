@@ -0,0 +1,246 @@
+use std::fmt;
+use std::fs;
+use std::io::Read;
+use std::path::PathBuf;
+
+use hmac::Hmac;
+use sha1::Sha1;
+use zeroize::Zeroizing;
+
+use super::*;
+
+use libzfs::zfs_prop_t::*;
+use property::KeyFormat;
+
+const DEFAULT_PBKDF2_ITERATIONS: u64 = 350_000;
+const WRAPPING_KEY_LEN: usize = 32;
+const MIN_PASSPHRASE_LEN: usize = 8;
+const MAX_PASSPHRASE_LEN: usize = 512;
+
+const URANDOM: &str = "/dev/urandom";
+
+/// Key material of an encrypted dataset, wiped from memory on drop
+#[derive(Clone)]
+pub struct KeyMaterial(Zeroizing<Vec<u8>>);
+
+impl KeyMaterial {
+    fn as_bytes(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+impl fmt::Debug for KeyMaterial {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("KeyMaterial(..)")
+    }
+}
+
+impl From<Vec<u8>> for KeyMaterial {
+    fn from(material: Vec<u8>) -> Self {
+        Self(Zeroizing::new(material))
+    }
+}
+
+impl From<&[u8]> for KeyMaterial {
+    fn from(material: &[u8]) -> Self {
+        material.to_vec().into()
+    }
+}
+
+impl From<String> for KeyMaterial {
+    fn from(material: String) -> Self {
+        material.into_bytes().into()
+    }
+}
+
+impl From<&str> for KeyMaterial {
+    fn from(material: &str) -> Self {
+        material.as_bytes().into()
+    }
+}
+
+/// Where the key material of an encrypted dataset comes from
+#[derive(Clone, Debug)]
+pub enum KeySource {
+    /// Supplied by the caller whenever the key is loaded (keylocation=prompt)
+    Prompt(KeyMaterial),
+    /// Read from a local file (keylocation=file://<path>)
+    File(PathBuf),
+}
+
+impl KeySource {
+    pub fn prompt(material: impl Into<KeyMaterial>) -> Self {
+        Self::Prompt(material.into())
+    }
+
+    pub fn file(path: impl Into<PathBuf>) -> Self {
+        Self::File(path.into())
+    }
+
+    fn keylocation(&self) -> String {
+        match self {
+            Self::Prompt(_) => "prompt".to_string(),
+            Self::File(path) => format!("file://{}", path.display()),
+        }
+    }
+
+    fn material(&self) -> Result<KeyMaterial> {
+        match self {
+            Self::Prompt(material) => Ok(material.clone()),
+            Self::File(path) => {
+                // Room for one byte past the limit, so that the buffer never reallocates
+                // and leaves copies of the key behind
+                let mut material = Zeroizing::new(Vec::with_capacity(MAX_PASSPHRASE_LEN + 2));
+                fs::File::open(path)?
+                    .take(MAX_PASSPHRASE_LEN as u64 + 1)
+                    .read_to_end(&mut material)?;
+                if material.len() > MAX_PASSPHRASE_LEN {
+                    return Err(invalid_key("key file is too long"));
+                }
+                Ok(KeyMaterial(material))
+            }
+        }
+    }
+}
+
+/// Wrapping key handed over to the kernel, wiped from memory on drop
+pub(crate) struct WrappingKey(Zeroizing<[u8; WRAPPING_KEY_LEN]>);
+
+impl WrappingKey {
+    /// Derive the wrapping key from `material` the same way libzfs does
+    pub(crate) fn derive(
+        format: KeyFormat,
+        material: &KeyMaterial,
+        salt: u64,
+        iters: u64,
+    ) -> Result<Self> {
+        let mut key = Zeroizing::new([0; WRAPPING_KEY_LEN]);
+        match format {
+            KeyFormat::None => return Err(invalid_key("keyformat is none")),
+            KeyFormat::Raw => {
+                if material.as_bytes().len() != WRAPPING_KEY_LEN {
+                    return Err(invalid_key("raw key must be 32 bytes"));
+                }
+                key.copy_from_slice(material.as_bytes());
+            }
+            KeyFormat::Hex => {
+                let hex = trim_newline(material.as_bytes());
+                if hex.len() != WRAPPING_KEY_LEN * 2 {
+                    return Err(invalid_key("hex key must be 64 characters"));
+                }
+                for (byte, digits) in key.iter_mut().zip(hex.chunks(2)) {
+                    *byte = (hex_digit(digits[0])? << 4) | hex_digit(digits[1])?;
+                }
+            }
+            KeyFormat::Passphrase => {
+                let passphrase = trim_newline(material.as_bytes());
+                if !(MIN_PASSPHRASE_LEN..=MAX_PASSPHRASE_LEN).contains(&passphrase.len()) {
+                    return Err(invalid_key("passphrase must be 8 to 512 characters"));
+                }
+                let rounds =
+                    u32::try_from(iters).map_err(|_| invalid_key("too many pbkdf2 iterations"))?;
+                // libzfs hashes the salt in little endian order whatever the host
+                pbkdf2::pbkdf2::<Hmac<Sha1>>(
+                    passphrase,
+                    &salt.to_le_bytes(),
+                    rounds,
+                    key.as_mut_slice(),
+                );
+            }
+        }
+        Ok(Self(key))
+    }
+
+    /// Fill in the key properties of `props` for a new key in `format` from `source`
+    /// and derive the matching wrapping key
+    pub(crate) fn generate(
+        props: &mut Properties,
+        format: KeyFormat,
+        source: &KeySource,
+    ) -> Result<Self> {
+        let (salt, iters) = if format == KeyFormat::Passphrase {
+            (random_salt()?, DEFAULT_PBKDF2_ITERATIONS)
+        } else {
+            (0, 0)
+        };
+        let key = Self::derive(format, &source.material()?, salt, iters)?;
+
+        props.keyformat(format);
+        props.keylocation(source.keylocation());
+        if format == KeyFormat::Passphrase {
+            props.pbkdf2salt(salt);
+            props.pbkdf2iters(iters);
+        }
+
+        Ok(key)
+    }
+
+    pub(crate) fn as_bytes(&self) -> &[u8] {
+        self.0.as_slice()
+    }
+}
+
+impl fmt::Debug for WrappingKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("WrappingKey(..)")
+    }
+}
+
+/// Encryption requested for a new dataset
+#[derive(Debug)]
+pub(crate) struct Crypt {
+    pub(crate) encryption: property::Encryption,
+    pub(crate) format: KeyFormat,
+    pub(crate) source: KeySource,
+}
+
+impl Crypt {
+    /// Fill in the encryption properties of `props` and derive the wrapping key
+    pub(crate) fn apply(&self, props: &mut Properties) -> Result<WrappingKey> {
+        props.encryption(self.encryption);
+        WrappingKey::generate(props, self.format, &self.source)
+    }
+}
+
+/// Load the wrapping key of `dataset` derived from `material`
+pub(crate) fn load_key(dataset: &libzfs::ZfsHandle, material: &KeyMaterial) -> Result<()> {
+    let format = dataset.numeric_property(ZFS_PROP_KEYFORMAT).into();
+    let salt = dataset.numeric_property(ZFS_PROP_PBKDF2_SALT);
+    let iters = dataset.numeric_property(ZFS_PROP_PBKDF2_ITERS);
+    let key = WrappingKey::derive(format, material, salt, iters)?;
+    lzc::load_key(dataset.name(), false, key.as_bytes())?;
+    Ok(())
+}
+
+/// Rewrap the master key of `dataset` with a new key in `format` from `source`
+pub(crate) fn change_key(
+    dataset: &libzfs::ZfsHandle,
+    format: KeyFormat,
+    source: &KeySource,
+) -> Result<()> {
+    let mut props = Properties::new();
+    let key = WrappingKey::generate(&mut props, format, source)?;
+    lzc::change_key(dataset.name(), props.into_inner(), key.as_bytes())?;
+    Ok(())
+}
+
+fn random_salt() -> Result<u64> {
+    let mut salt = [0; 8];
+    fs::File::open(URANDOM)?.read_exact(&mut salt)?;
+    Ok(u64::from_ne_bytes(salt))
+}
+
+fn trim_newline(material: &[u8]) -> &[u8] {
+    material.strip_suffix(b"\n").unwrap_or(material)
+}
+
+fn hex_digit(digit: u8) -> Result<u8> {
+    char::from(digit)
+        .to_digit(16)
+        .map(|digit| digit as u8)
+        .ok_or_else(|| invalid_key("hex key must contain only hex digits"))
+}
+
+fn invalid_key(reason: &str) -> DatasetError {
+    DatasetError::InvalidKey(reason.to_string())
+}
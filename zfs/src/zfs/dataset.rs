@@ -9,6 +9,7 @@ pub use filesystem::Filesystem;
 pub use filesystem::FilesystemBuilder;
 pub use snapshot::Snapshot;
 pub use snapshot::SnapshotBuilder;
+pub use snapshot::SnapshotSet;
 pub use volume::Volume;
 pub use volume::VolumeBuilder;
 
@@ -16,3 +17,29 @@ mod bookmark;
 mod filesystem;
 mod snapshot;
 mod volume;
+
+/// A filesystem or a volume
+#[derive(Debug)]
+pub enum Dataset {
+    Filesystem(Filesystem),
+    Volume(Volume),
+}
+
+impl Dataset {
+    pub fn get(name: impl AsRef<str>) -> Result<Self> {
+        let name = name.as_ref();
+        let cname = ffi::CString::new(name)?;
+        if libzfs::ZfsHandle::new(cname)?.is_volume() {
+            Volume::get(name).map(Self::Volume)
+        } else {
+            Filesystem::get(name).map(Self::Filesystem)
+        }
+    }
+
+    pub fn name(&self) -> String {
+        match self {
+            Self::Filesystem(filesystem) => filesystem.name(),
+            Self::Volume(volume) => volume.name(),
+        }
+    }
+}
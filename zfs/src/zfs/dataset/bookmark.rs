@@ -1,41 +1,113 @@
-use super::*;
+use std::collections::BTreeMap;
 
-use libzfs::zfs_prop_t::*;
+use super::*;
 
-#[derive(Debug)]
+#[derive(Clone, Debug, PartialEq, Eq)]
 pub struct Bookmark {
-    dataset: libzfs::ZfsHandle,
+    name: String,
+    guid: u64,
+    createtxg: u64,
+    creation: u64,
+    redact_snaps: Vec<u64>,
+    redact_complete: bool,
+    ivset_guid: Option<u64>,
 }
 
 impl Bookmark {
     pub fn get(name: impl AsRef<str>) -> Result<Self> {
-        let name = ffi::CString::new(name.as_ref())?;
-        let dataset = libzfs::ZfsHandle::new(name)?;
+        let name = name.as_ref();
+        let props = lzc::get_bookmark_props(name)?;
+
+        Ok(Self::from_props(name, props))
+    }
+
+    pub(crate) fn list(filesystem: impl AsRef<str>) -> Result<Vec<Self>> {
+        let filesystem = filesystem.as_ref();
+        let props = [
+            property::GUID.as_ref(),
+            property::CREATETXG.as_ref(),
+            property::CREATION.as_ref(),
+            property::REDACT_SNAPS.as_ref(),
+            property::REDACT_COMPLETE.as_ref(),
+            property::IVSETGUID.as_ref(),
+        ];
+        let bookmarks = lzc::get_bookmarks(filesystem, props)?
+            .into_iter()
+            .map(|(bookmark, props)| Self::from_props(format!("{filesystem}#{bookmark}"), props))
+            .collect();
 
-        Ok(Self { dataset })
+        Ok(bookmarks)
     }
 
     pub fn destroy(self) -> Result<()> {
-        lzc::destroy_dataset(self.name())?;
+        lzc::destroy_bookmarks([self.name])?;
         Ok(())
     }
 
     pub fn name(&self) -> String {
-        self.dataset.name().to_string()
+        self.name.clone()
     }
 
     #[inline]
     pub fn guid(&self) -> u64 {
-        self.dataset.numeric_property(ZFS_PROP_GUID)
+        self.guid
     }
 
     #[inline]
     pub fn creation(&self) -> u64 {
-        self.dataset.numeric_property(ZFS_PROP_CREATION)
+        self.creation
     }
 
     #[inline]
     pub fn createtxg(&self) -> u64 {
-        self.dataset.numeric_property(ZFS_PROP_CREATETXG)
+        self.createtxg
+    }
+
+    /// GUIDs of the snapshots redacted by this bookmark (empty unless it is a redaction bookmark)
+    #[inline]
+    pub fn redact_snaps(&self) -> &[u64] {
+        &self.redact_snaps
+    }
+
+    /// Whether the redaction list of this redaction bookmark has been fully computed,
+    /// i.e. whether it can be used for a redacted send
+    #[inline]
+    pub fn redact_complete(&self) -> bool {
+        self.redact_complete
+    }
+
+    /// IV set GUID of the bookmarked encrypted snapshot
+    #[inline]
+    pub fn ivset_guid(&self) -> Option<u64> {
+        self.ivset_guid
+    }
+
+    fn from_props(name: impl ToString, mut props: BTreeMap<String, nvpair::Value>) -> Self {
+        let mut numeric = |prop: &str| match props.remove(prop) {
+            Some(nvpair::Value::U64(value)) => Some(value),
+            _ => None,
+        };
+        let guid = numeric(&property::GUID).unwrap_or_default();
+        let createtxg = numeric(&property::CREATETXG).unwrap_or_default();
+        let creation = numeric(&property::CREATION).unwrap_or_default();
+        let ivset_guid = numeric(&property::IVSETGUID);
+        let redact_complete = matches!(
+            props.remove(property::REDACT_COMPLETE.as_ref()),
+            Some(nvpair::Value::Boolean(true))
+        );
+        let redact_snaps = match props.remove(property::REDACT_SNAPS.as_ref()) {
+            Some(nvpair::Value::U64Array(snaps)) => snaps,
+            _ => Vec::new(),
+        };
+
+        Self {
+            name: name.to_string(),
+            guid,
+            createtxg,
+            creation,
+            redact_snaps,
+            redact_complete,
+            ivset_guid,
+        }
     }
 }
@@ -1,3 +1,7 @@
+use std::collections::BTreeMap;
+use std::fmt;
+use std::str;
+
 use serde::ser::{Serialize, SerializeStruct, Serializer};
 
 use super::*;
@@ -27,18 +31,23 @@ impl Filesystem {
     }
 
     pub fn destroy_recursive(&self) -> Result<()> {
-        let ns_datasets = libzfs::zfs_list_from(self.name())
+        let (snapshots, datasets): (Vec<_>, Vec<_>) = libzfs::zfs_list_from(self.name())
             .filesystems()
             .volumes()
             .snapshots()
             .recursive(true)
-            .get_collection();
+            .iter()
+            .partition(|dataset| dataset.is_snapshot());
+
+        if !snapshots.is_empty() {
+            lzc::destroy_snapshots(snapshots.iter().map(|snapshot| snapshot.name()), false)?;
+        }
 
-        for dataset in ns_datasets.into_iter() {
+        // The walk starts with this filesystem and lists parents before their children
+        for dataset in datasets.iter().rev() {
             lzc::destroy_dataset(dataset.name())?;
         }
 
-        lzc::destroy_dataset(self.name())?;
         Ok(())
     }
 
@@ -46,6 +55,127 @@ impl Filesystem {
         self.dataset.name().to_string()
     }
 
+    /// Load the wrapping key of this encryption root from `key` (raw, hex or passphrase)
+    pub fn load_key(&self, key: &[u8]) -> Result<()> {
+        crypto::load_key(&self.dataset, &key.into())
+    }
+
+    pub fn unload_key(&self) -> Result<()> {
+        lzc::unload_key(self.name())?;
+        Ok(())
+    }
+
+    /// Rewrap the master key of this encryption root with a new key.
+    /// The current key must be loaded.
+    pub fn change_key(&self, format: property::KeyFormat, source: KeySource) -> Result<()> {
+        crypto::change_key(&self.dataset, format, &source)
+    }
+
+    /// Make this filesystem inherit the key of its parent encryption root
+    pub fn inherit_key(&self) -> Result<()> {
+        lzc::inherit_key(self.name())?;
+        Ok(())
+    }
+
+    /// Promote this clone so that it no longer depends on its origin snapshot.
+    /// Fails with `DatasetError::PromoteConflict` naming the snapshot of this filesystem
+    /// that has the same name as one of the origin's.
+    pub fn promote(&self) -> Result<()> {
+        lzc::promote(self.name()).map_err(|err| match err.errors.first() {
+            Some((snapshot, _)) => DatasetError::PromoteConflict(snapshot.clone()),
+            None => lzc::LzcError::from(err).into(),
+        })
+    }
+
+    /// Roll back to the most recent snapshot, returning its name
+    pub fn rollback(&self) -> Result<String> {
+        let snapshot = lzc::rollback(self.name())?;
+        Ok(snapshot)
+    }
+
+    /// Roll back to `snapshot`, which has to be the most recent snapshot of this filesystem.
+    /// Nothing is destroyed otherwise.
+    pub fn rollback_to(&self, snapshot: &Snapshot) -> Result<()> {
+        let snapshot = snapshot.name();
+        if self.latest_snapshot().as_ref() != Some(&snapshot) {
+            return Err(DatasetError::NotLatestSnapshot(snapshot));
+        }
+        lzc::rollback_to(self.name(), snapshot)?;
+        Ok(())
+    }
+
+    /// Block until no `activity` (`ZFS_WAIT_DELETEQ`) is in progress,
+    /// returning whether it had to wait
+    #[cfg(feature = "wait")]
+    pub fn wait(&self, activity: zfs_wait_activity_t) -> Result<bool> {
+        let waited = lzc::wait_fs(self.name(), activity)?;
+        Ok(waited)
+    }
+
+    /// Same as `wait()`, blocking a thread of the tokio blocking pool instead of the caller
+    #[cfg(feature = "wait")]
+    pub fn wait_async(
+        &self,
+        activity: zfs_wait_activity_t,
+    ) -> impl std::future::Future<Output = Result<bool>> {
+        let name = self.name();
+        wait::spawn_blocking(move || lzc::wait_fs(name, activity))
+    }
+
+    /// Value of `property` along with where it comes from (local, inherited, default...)
+    pub fn property_with_source<'a, T: str::FromStr>(
+        &self,
+        property: impl Property<'a>,
+    ) -> Result<PropertyValue<T>> {
+        PropertyValue::get(&self.dataset, property)
+    }
+
+    /// Clear the local value of `property` so that it is inherited from the parent again,
+    /// or, with `received`, revert it to the value received with the last `zfs receive`
+    pub fn inherit<'a>(&mut self, property: impl Property<'a>, received: bool) -> Result<()> {
+        self.dataset.inherit_property(property.name(), received)?;
+        Ok(())
+    }
+
+    /// User properties (module:property) set on or inherited by this filesystem
+    pub fn user_properties(&self) -> BTreeMap<String, PropertyValue<String>> {
+        property::user_properties(&self.dataset)
+    }
+
+    /// Set user property `property` (module:property) on this filesystem
+    pub fn set_user_property(
+        &mut self,
+        property: impl AsRef<str>,
+        value: impl AsRef<str>,
+    ) -> Result<()> {
+        property::set_user_property(&mut self.dataset, property.as_ref(), value.as_ref())
+    }
+
+    /// Clear user property `property` from this filesystem so that it is inherited again
+    pub fn inherit_user_property(&mut self, property: impl AsRef<str>) -> Result<()> {
+        property::inherit_user_property(&mut self.dataset, property.as_ref())
+    }
+
+    /// Space and objects used by each user, group or project, with their quotas,
+    /// like `zfs userspace`
+    pub fn userspace(&self, kind: UserquotaProp) -> Result<impl Iterator<Item = SpaceEntry>> {
+        userspace::userspace(&self.dataset, kind)
+    }
+
+    /// All the bookmarks of this filesystem
+    pub fn bookmarks(&self) -> Result<Vec<Bookmark>> {
+        Bookmark::list(self.name())
+    }
+
+    /// Token of an interrupted resumable receive into this filesystem, see `Zfs::send_resume()`
+    pub fn receive_resume_token(&self) -> Result<Option<ResumeToken>> {
+        self.dataset
+            .literal_property(ZFS_PROP_RECEIVE_RESUME_TOKEN)
+            .filter(|token| !token.is_empty() && token != "-")
+            .map(ResumeToken::decode)
+            .transpose()
+    }
+
     #[inline]
     pub fn available(&self) -> u64 {
         self.dataset.numeric_property(ZFS_PROP_AVAILABLE)
@@ -121,6 +251,21 @@ impl Filesystem {
         self.dataset.numeric_property(ZFS_PROP_CHECKSUM).into()
     }
 
+    #[inline]
+    pub fn encryption(&self) -> property::Encryption {
+        self.dataset.numeric_property(ZFS_PROP_ENCRYPTION).into()
+    }
+
+    #[inline]
+    pub fn keyformat(&self) -> property::KeyFormat {
+        self.dataset.numeric_property(ZFS_PROP_KEYFORMAT).into()
+    }
+
+    #[inline]
+    pub fn keystatus(&self) -> property::KeyStatus {
+        self.dataset.numeric_property(ZFS_PROP_KEYSTATUS).into()
+    }
+
     #[inline]
     pub fn compression(&self) -> property::Compression {
         self.dataset.numeric_property(ZFS_PROP_COMPRESSION).into()
@@ -166,6 +311,205 @@ impl Filesystem {
         self.dataset.numeric_property(ZFS_PROP_OBJSETID)
     }
 
+    #[inline]
+    pub fn quota(&self) -> property::Size {
+        self.dataset.numeric_property(ZFS_PROP_QUOTA).into()
+    }
+
+    #[inline]
+    pub fn refquota(&self) -> property::Size {
+        self.dataset.numeric_property(ZFS_PROP_REFQUOTA).into()
+    }
+
+    #[inline]
+    pub fn reservation(&self) -> property::Size {
+        self.dataset.numeric_property(ZFS_PROP_RESERVATION).into()
+    }
+
+    #[inline]
+    pub fn refreservation(&self) -> property::Size {
+        self.dataset
+            .numeric_property(ZFS_PROP_REFRESERVATION)
+            .into()
+    }
+
+    #[inline]
+    pub fn sync(&self) -> property::SyncMode {
+        self.dataset.numeric_property(ZFS_PROP_SYNC).into()
+    }
+
+    #[inline]
+    pub fn logbias(&self) -> property::LogBias {
+        self.dataset.numeric_property(ZFS_PROP_LOGBIAS).into()
+    }
+
+    #[inline]
+    pub fn primarycache(&self) -> property::Cache {
+        self.dataset.numeric_property(ZFS_PROP_PRIMARYCACHE).into()
+    }
+
+    #[inline]
+    pub fn secondarycache(&self) -> property::Cache {
+        self.dataset
+            .numeric_property(ZFS_PROP_SECONDARYCACHE)
+            .into()
+    }
+
+    #[inline]
+    pub fn dedup(&self) -> property::Dedup {
+        self.dataset.numeric_property(ZFS_PROP_DEDUP).into()
+    }
+
+    #[inline]
+    pub fn recordsize(&self) -> u64 {
+        self.dataset.numeric_property(ZFS_PROP_RECORDSIZE)
+    }
+
+    #[inline]
+    pub fn special_small_blocks(&self) -> u64 {
+        self.dataset.numeric_property(ZFS_PROP_SPECIAL_SMALL_BLOCKS)
+    }
+
+    #[inline]
+    pub fn copies(&self) -> u64 {
+        self.dataset.numeric_property(ZFS_PROP_COPIES)
+    }
+
+    #[inline]
+    pub fn xattr(&self) -> property::Xattr {
+        self.dataset.numeric_property(ZFS_PROP_XATTR).into()
+    }
+
+    #[inline]
+    pub fn acltype(&self) -> property::AclType {
+        self.dataset.numeric_property(ZFS_PROP_ACLTYPE).into()
+    }
+
+    #[inline]
+    pub fn aclinherit(&self) -> property::AclInherit {
+        self.dataset.numeric_property(ZFS_PROP_ACLINHERIT).into()
+    }
+
+    #[inline]
+    pub fn aclmode(&self) -> property::AclMode {
+        self.dataset.numeric_property(ZFS_PROP_ACLMODE).into()
+    }
+
+    #[inline]
+    pub fn dnodesize(&self) -> property::DnodeSize {
+        self.dataset.numeric_property(ZFS_PROP_DNODESIZE).into()
+    }
+
+    #[inline]
+    pub fn snapdir(&self) -> property::SnapDir {
+        self.dataset.numeric_property(ZFS_PROP_SNAPDIR).into()
+    }
+
+    #[inline]
+    pub fn redundant_metadata(&self) -> property::RedundantMetadata {
+        self.dataset
+            .numeric_property(ZFS_PROP_REDUNDANT_METADATA)
+            .into()
+    }
+
+    #[inline]
+    pub fn casesensitivity(&self) -> property::CaseSensitivity {
+        self.dataset.numeric_property(ZFS_PROP_CASE).into()
+    }
+
+    #[inline]
+    pub fn normalization(&self) -> property::Normalization {
+        self.dataset.numeric_property(ZFS_PROP_NORMALIZE).into()
+    }
+
+    #[inline]
+    pub fn utf8only(&self) -> property::OnOff {
+        self.dataset.numeric_property(ZFS_PROP_UTF8ONLY).into()
+    }
+
+    #[inline]
+    pub fn sharenfs(&self) -> String {
+        self.dataset
+            .literal_property(ZFS_PROP_SHARENFS)
+            .unwrap_or_default()
+    }
+
+    #[inline]
+    pub fn sharesmb(&self) -> String {
+        self.dataset
+            .literal_property(ZFS_PROP_SHARESMB)
+            .unwrap_or_default()
+    }
+
+    /// Limit on the number of filesystems under this one, `None` when there is no limit
+    #[inline]
+    pub fn filesystem_limit(&self) -> Option<u64> {
+        Some(self.dataset.numeric_property(ZFS_PROP_FILESYSTEM_LIMIT))
+            .filter(|limit| *limit != u64::MAX)
+    }
+
+    /// Limit on the number of snapshots under this filesystem, `None` when there is no limit
+    #[inline]
+    pub fn snapshot_limit(&self) -> Option<u64> {
+        Some(self.dataset.numeric_property(ZFS_PROP_SNAPSHOT_LIMIT))
+            .filter(|limit| *limit != u64::MAX)
+    }
+
+    #[inline]
+    pub fn filesystem_count(&self) -> u64 {
+        self.dataset.numeric_property(ZFS_PROP_FILESYSTEM_COUNT)
+    }
+
+    #[inline]
+    pub fn snapshot_count(&self) -> u64 {
+        self.dataset.numeric_property(ZFS_PROP_SNAPSHOT_COUNT)
+    }
+
+    #[inline]
+    pub fn version(&self) -> u64 {
+        self.dataset.numeric_property(ZFS_PROP_VERSION)
+    }
+
+    #[inline]
+    pub fn usedbysnapshots(&self) -> u64 {
+        self.dataset.numeric_property(ZFS_PROP_USEDSNAP)
+    }
+
+    #[inline]
+    pub fn usedbydataset(&self) -> u64 {
+        self.dataset.numeric_property(ZFS_PROP_USEDDS)
+    }
+
+    #[inline]
+    pub fn usedbychildren(&self) -> u64 {
+        self.dataset.numeric_property(ZFS_PROP_USEDCHILD)
+    }
+
+    #[inline]
+    pub fn usedbyrefreservation(&self) -> u64 {
+        self.dataset.numeric_property(ZFS_PROP_USEDREFRESERV)
+    }
+
+    #[inline]
+    pub fn written(&self) -> u64 {
+        self.dataset.numeric_property(ZFS_PROP_WRITTEN)
+    }
+
+    #[inline]
+    pub fn refcompressratio(&self) -> u64 {
+        self.dataset.numeric_property(ZFS_PROP_REFRATIO)
+    }
+
+    fn latest_snapshot(&self) -> Option<String> {
+        libzfs::zfs_list_from(self.name())
+            .snapshots()
+            .max_depth(1)
+            .sort_descending(SortBy::CreateTxg)
+            .iter()
+            .next()
+            .map(|snapshot| snapshot.name().to_string())
+    }
+
     pub fn get(name: impl AsRef<str>) -> Result<Self> {
         let cname = ffi::CString::new(name.as_ref())?;
         let dataset = libzfs::ZfsHandle::new(cname)?;
@@ -180,7 +524,7 @@ impl Serialize for Filesystem {
         S: Serializer,
     {
         dbg!("serializing filesystem");
-        let mut state = serializer.serialize_struct("Filesystem", 25)?;
+        let mut state = serializer.serialize_struct("Filesystem", 42)?;
         state.serialize_field(NAME.as_ref(), &self.name())?;
         state.serialize_field(AVAILABLE.as_ref(), &self.available())?;
         state.serialize_field(ATIME.as_ref(), &self.atime())?;
@@ -206,6 +550,23 @@ impl Serialize for Filesystem {
         state.serialize_field(REFERENCED.as_ref(), &self.referenced())?;
         state.serialize_field(LOGICALREFERENCED.as_ref(), &self.logicalreferenced())?;
         state.serialize_field(OBJSETID.as_ref(), &self.objsetid())?;
+        state.serialize_field(QUOTA.as_ref(), &self.quota())?;
+        state.serialize_field(REFQUOTA.as_ref(), &self.refquota())?;
+        state.serialize_field(RESERVATION.as_ref(), &self.reservation())?;
+        state.serialize_field(REFRESERVATION.as_ref(), &self.refreservation())?;
+        state.serialize_field(RECORDSIZE.as_ref(), &self.recordsize())?;
+        state.serialize_field(SPECIAL_SMALL_BLOCKS.as_ref(), &self.special_small_blocks())?;
+        state.serialize_field(SYNC.as_ref(), &self.sync())?;
+        state.serialize_field(XATTR.as_ref(), &self.xattr())?;
+        state.serialize_field(ACLTYPE.as_ref(), &self.acltype())?;
+        state.serialize_field(DNODESIZE.as_ref(), &self.dnodesize())?;
+        state.serialize_field(LOGBIAS.as_ref(), &self.logbias())?;
+        state.serialize_field(PRIMARYCACHE.as_ref(), &self.primarycache())?;
+        state.serialize_field(SECONDARYCACHE.as_ref(), &self.secondarycache())?;
+        state.serialize_field(DEDUP.as_ref(), &self.dedup())?;
+        state.serialize_field(COPIES.as_ref(), &self.copies())?;
+        state.serialize_field(SNAPDIR.as_ref(), &self.snapdir())?;
+        state.serialize_field(REDUNDANT_METADATA.as_ref(), &self.redundant_metadata())?;
         dbg!("serializing finished");
 
         state.end()
@@ -215,18 +576,31 @@ impl Serialize for Filesystem {
 #[derive(Debug)]
 pub struct FilesystemBuilder {
     props: Properties,
+    crypt: Option<crypto::Crypt>,
 }
 
 impl FilesystemBuilder {
     pub fn new() -> Self {
         let props = Properties::new();
-        Self { props }
+        let crypt = None;
+        Self { props, crypt }
     }
 
     // TODO: should check mount options and mount the FS if needed
-    pub fn create(self, name: impl AsRef<str>) -> Result<Filesystem> {
+    pub fn create(mut self, name: impl AsRef<str>) -> Result<Filesystem> {
         let cname = ffi::CString::new(name.as_ref())?;
-        libzfs::create_filesystem(name, self.props)?;
+        if let Some(crypt) = self.crypt {
+            let key = crypt.apply(&mut self.props)?;
+            // lzc takes the properties as is, so have libzfs validate them first
+            let props = libzfs::zfs_valid_proplist(
+                name.as_ref(),
+                libzfs::zfs_type_t::ZFS_TYPE_FILESYSTEM,
+                self.props,
+            )?;
+            lzc::create_filesystem_with_key(name, props, key.as_bytes())?;
+        } else {
+            libzfs::create_filesystem(name, self.props)?;
+        }
         let dataset = libzfs::ZfsHandle::new(cname)?;
         let filesystem = Filesystem { dataset };
 
@@ -303,6 +677,190 @@ impl FilesystemBuilder {
         self
     }
 
+    pub fn quota(mut self, value: impl Into<property::Size>) -> Self {
+        self.props.quota(value);
+        self
+    }
+
+    pub fn refquota(mut self, value: impl Into<property::Size>) -> Self {
+        self.props.refquota(value);
+        self
+    }
+
+    pub fn reservation(mut self, value: impl Into<property::Size>) -> Self {
+        self.props.reservation(value);
+        self
+    }
+
+    pub fn refreservation(mut self, value: impl Into<property::Size>) -> Self {
+        self.props.refreservation(value);
+        self
+    }
+
+    pub fn sync(mut self, value: impl Into<property::SyncMode>) -> Self {
+        self.props.sync(value);
+        self
+    }
+
+    pub fn logbias(mut self, value: impl Into<property::LogBias>) -> Self {
+        self.props.logbias(value);
+        self
+    }
+
+    pub fn primarycache(mut self, value: impl Into<property::Cache>) -> Self {
+        self.props.primarycache(value);
+        self
+    }
+
+    pub fn secondarycache(mut self, value: impl Into<property::Cache>) -> Self {
+        self.props.secondarycache(value);
+        self
+    }
+
+    pub fn dedup(mut self, value: impl Into<property::Dedup>) -> Self {
+        self.props.dedup(value);
+        self
+    }
+
+    pub fn copies(mut self, value: u64) -> Self {
+        self.props.copies(value);
+        self
+    }
+
+    pub fn redundant_metadata(mut self, value: impl Into<property::RedundantMetadata>) -> Self {
+        self.props.redundant_metadata(value);
+        self
+    }
+
+    pub fn recordsize(mut self, value: u64) -> Self {
+        self.props.recordsize(value);
+        self
+    }
+
+    pub fn special_small_blocks(mut self, value: u64) -> Self {
+        self.props.special_small_blocks(value);
+        self
+    }
+
+    pub fn xattr(mut self, value: impl Into<property::Xattr>) -> Self {
+        self.props.xattr(value);
+        self
+    }
+
+    pub fn acltype(mut self, value: impl Into<property::AclType>) -> Self {
+        self.props.acltype(value);
+        self
+    }
+
+    pub fn aclinherit(mut self, value: impl Into<property::AclInherit>) -> Self {
+        self.props.aclinherit(value);
+        self
+    }
+
+    pub fn aclmode(mut self, value: impl Into<property::AclMode>) -> Self {
+        self.props.aclmode(value);
+        self
+    }
+
+    pub fn dnodesize(mut self, value: impl Into<property::DnodeSize>) -> Self {
+        self.props.dnodesize(value);
+        self
+    }
+
+    pub fn snapdir(mut self, value: impl Into<property::SnapDir>) -> Self {
+        self.props.snapdir(value);
+        self
+    }
+
+    pub fn sharenfs(mut self, value: impl AsRef<str>) -> Self {
+        self.props.sharenfs(value);
+        self
+    }
+
+    pub fn sharesmb(mut self, value: impl AsRef<str>) -> Self {
+        self.props.sharesmb(value);
+        self
+    }
+
+    pub fn filesystem_limit(mut self, value: Option<u64>) -> Self {
+        self.props.filesystem_limit(value);
+        self
+    }
+
+    pub fn snapshot_limit(mut self, value: Option<u64>) -> Self {
+        self.props.snapshot_limit(value);
+        self
+    }
+
+    pub fn casesensitivity(mut self, value: impl Into<property::CaseSensitivity>) -> Self {
+        self.props.casesensitivity(value);
+        self
+    }
+
+    pub fn normalization(mut self, value: impl Into<property::Normalization>) -> Self {
+        self.props.normalization(value);
+        self
+    }
+
+    pub fn utf8only(mut self, value: impl Into<property::OnOff>) -> Self {
+        self.props.utf8only(value);
+        self
+    }
+
+    /// Create the filesystem as a new encryption root with its key from `source`
+    pub fn encryption(
+        mut self,
+        encryption: impl Into<property::Encryption>,
+        format: property::KeyFormat,
+        source: KeySource,
+    ) -> Self {
+        let encryption = encryption.into();
+        self.crypt = Some(crypto::Crypt {
+            encryption,
+            format,
+            source,
+        });
+        self
+    }
+
+    pub fn userquota(mut self, user: impl fmt::Display, quota: impl Into<property::Size>) -> Self {
+        self.props.userquota(user, quota);
+        self
+    }
+
+    pub fn groupquota(
+        mut self,
+        group: impl fmt::Display,
+        quota: impl Into<property::Size>,
+    ) -> Self {
+        self.props.groupquota(group, quota);
+        self
+    }
+
+    pub fn projectquota(
+        mut self,
+        project: impl fmt::Display,
+        quota: impl Into<property::Size>,
+    ) -> Self {
+        self.props.projectquota(project, quota);
+        self
+    }
+
+    pub fn userobjquota(mut self, user: impl fmt::Display, quota: Option<u64>) -> Self {
+        self.props.userobjquota(user, quota);
+        self
+    }
+
+    pub fn groupobjquota(mut self, group: impl fmt::Display, quota: Option<u64>) -> Self {
+        self.props.groupobjquota(group, quota);
+        self
+    }
+
+    pub fn projectobjquota(mut self, project: impl fmt::Display, quota: Option<u64>) -> Self {
+        self.props.projectobjquota(project, quota);
+        self
+    }
+
     pub fn property(mut self, property: &str, value: &str) -> Self {
         self.props.string_property(property, value);
         self
@@ -394,6 +952,159 @@ impl<'a> FilesytemPropSetter<'a> {
         self
     }
 
+    pub fn quota(mut self, value: impl Into<property::Size>) -> Self {
+        self.props.quota(value);
+        self
+    }
+
+    pub fn refquota(mut self, value: impl Into<property::Size>) -> Self {
+        self.props.refquota(value);
+        self
+    }
+
+    pub fn reservation(mut self, value: impl Into<property::Size>) -> Self {
+        self.props.reservation(value);
+        self
+    }
+
+    pub fn refreservation(mut self, value: impl Into<property::Size>) -> Self {
+        self.props.refreservation(value);
+        self
+    }
+
+    pub fn sync(mut self, value: impl Into<property::SyncMode>) -> Self {
+        self.props.sync(value);
+        self
+    }
+
+    pub fn logbias(mut self, value: impl Into<property::LogBias>) -> Self {
+        self.props.logbias(value);
+        self
+    }
+
+    pub fn primarycache(mut self, value: impl Into<property::Cache>) -> Self {
+        self.props.primarycache(value);
+        self
+    }
+
+    pub fn secondarycache(mut self, value: impl Into<property::Cache>) -> Self {
+        self.props.secondarycache(value);
+        self
+    }
+
+    pub fn dedup(mut self, value: impl Into<property::Dedup>) -> Self {
+        self.props.dedup(value);
+        self
+    }
+
+    pub fn copies(mut self, value: u64) -> Self {
+        self.props.copies(value);
+        self
+    }
+
+    pub fn redundant_metadata(mut self, value: impl Into<property::RedundantMetadata>) -> Self {
+        self.props.redundant_metadata(value);
+        self
+    }
+
+    pub fn recordsize(mut self, value: u64) -> Self {
+        self.props.recordsize(value);
+        self
+    }
+
+    pub fn special_small_blocks(mut self, value: u64) -> Self {
+        self.props.special_small_blocks(value);
+        self
+    }
+
+    pub fn xattr(mut self, value: impl Into<property::Xattr>) -> Self {
+        self.props.xattr(value);
+        self
+    }
+
+    pub fn acltype(mut self, value: impl Into<property::AclType>) -> Self {
+        self.props.acltype(value);
+        self
+    }
+
+    pub fn aclinherit(mut self, value: impl Into<property::AclInherit>) -> Self {
+        self.props.aclinherit(value);
+        self
+    }
+
+    pub fn aclmode(mut self, value: impl Into<property::AclMode>) -> Self {
+        self.props.aclmode(value);
+        self
+    }
+
+    pub fn dnodesize(mut self, value: impl Into<property::DnodeSize>) -> Self {
+        self.props.dnodesize(value);
+        self
+    }
+
+    pub fn snapdir(mut self, value: impl Into<property::SnapDir>) -> Self {
+        self.props.snapdir(value);
+        self
+    }
+
+    pub fn sharenfs(mut self, value: impl AsRef<str>) -> Self {
+        self.props.sharenfs(value);
+        self
+    }
+
+    pub fn sharesmb(mut self, value: impl AsRef<str>) -> Self {
+        self.props.sharesmb(value);
+        self
+    }
+
+    pub fn filesystem_limit(mut self, value: Option<u64>) -> Self {
+        self.props.filesystem_limit(value);
+        self
+    }
+
+    pub fn snapshot_limit(mut self, value: Option<u64>) -> Self {
+        self.props.snapshot_limit(value);
+        self
+    }
+
+    pub fn userquota(mut self, user: impl fmt::Display, quota: impl Into<property::Size>) -> Self {
+        self.props.userquota(user, quota);
+        self
+    }
+
+    pub fn groupquota(
+        mut self,
+        group: impl fmt::Display,
+        quota: impl Into<property::Size>,
+    ) -> Self {
+        self.props.groupquota(group, quota);
+        self
+    }
+
+    pub fn projectquota(
+        mut self,
+        project: impl fmt::Display,
+        quota: impl Into<property::Size>,
+    ) -> Self {
+        self.props.projectquota(project, quota);
+        self
+    }
+
+    pub fn userobjquota(mut self, user: impl fmt::Display, quota: Option<u64>) -> Self {
+        self.props.userobjquota(user, quota);
+        self
+    }
+
+    pub fn groupobjquota(mut self, group: impl fmt::Display, quota: Option<u64>) -> Self {
+        self.props.groupobjquota(group, quota);
+        self
+    }
+
+    pub fn projectobjquota(mut self, project: impl fmt::Display, quota: Option<u64>) -> Self {
+        self.props.projectobjquota(project, quota);
+        self
+    }
+
     pub fn commit(self) -> Result<()> {
         self.filesystem.dataset.set_properties(self.props)?;
         Ok(())
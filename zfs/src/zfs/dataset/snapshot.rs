@@ -1,3 +1,7 @@
+use std::collections::{BTreeMap, BTreeSet};
+use std::os::unix::io::AsRawFd;
+use std::time::{Duration, SystemTime};
+
 use super::*;
 
 use libzfs::zfs_prop_t::*;
@@ -15,8 +19,18 @@ impl Snapshot {
         Ok(Self { dataset })
     }
 
+    pub fn set(&mut self) -> SnapshotPropSetter<'_> {
+        SnapshotPropSetter::new(self)
+    }
+
     pub fn destroy(self) -> Result<()> {
-        lzc::destroy_dataset(self.name())?;
+        lzc::destroy_snapshots([self.name()], false)?;
+        Ok(())
+    }
+
+    /// Destroy this snapshot, or mark it for destruction once its holds and clones are gone
+    pub fn destroy_deferred(self) -> Result<()> {
+        lzc::destroy_snapshots([self.name()], true)?;
         Ok(())
     }
 
@@ -24,6 +38,84 @@ impl Snapshot {
         self.dataset.name().to_string()
     }
 
+    /// Place user hold `tag` on this snapshot
+    pub fn hold(&self, tag: impl AsRef<str>) -> Result<()> {
+        let skipped = lzc::hold([(self.name(), tag)])?;
+        skipped_to_result(skipped)
+    }
+
+    /// Place user hold `tag` on this snapshot, which is released once `cleanup` is closed.
+    /// Use `Zfs::cleanup_fd()` to obtain a suitable descriptor.
+    pub fn hold_with_cleanup(&self, tag: impl AsRef<str>, cleanup: impl AsRawFd) -> Result<()> {
+        let skipped = lzc::hold_with_cleanup([(self.name(), tag)], cleanup)?;
+        skipped_to_result(skipped)
+    }
+
+    /// Release user hold `tag` from this snapshot
+    pub fn release(&self, tag: impl AsRef<str>) -> Result<()> {
+        let skipped = lzc::release([(self.name(), tag)])?;
+        skipped_to_result(skipped)
+    }
+
+    /// User holds on this snapshot with the time each hold was placed
+    pub fn holds(&self) -> Result<BTreeMap<String, SystemTime>> {
+        let holds = lzc::get_holds(self.name())?
+            .into_iter()
+            .map(|(tag, created)| (tag, SystemTime::UNIX_EPOCH + Duration::from_secs(created)))
+            .collect();
+        Ok(holds)
+    }
+
+    /// Create redaction bookmark `bookmark` (the part after '#') of this snapshot, which leaves
+    /// out of redacted sends whatever was changed in `redaction_snapshots`, snapshots of clones
+    /// of this snapshot
+    pub fn redact(
+        &self,
+        bookmark: impl AsRef<str>,
+        redaction_snapshots: impl IntoIterator<Item = impl AsRef<str>>,
+    ) -> Result<Bookmark> {
+        let name = self.name();
+        let bookmark = bookmark.as_ref();
+        lzc::redact(&name, bookmark, redaction_snapshots)?;
+        let filesystem = name
+            .split_once('@')
+            .map_or(name.as_str(), |(filesystem, _)| filesystem);
+        Bookmark::get(format!("{filesystem}#{bookmark}"))
+    }
+
+    /// Clone this snapshot into a new dataset `name`, a volume if the snapshot is of a volume
+    /// or a filesystem otherwise
+    pub fn clone_to(&self, name: impl AsRef<str>, props: Properties) -> Result<Dataset> {
+        let name = name.as_ref();
+        lzc::clone(name, self.name(), props.into_inner())?;
+        Dataset::get(name)
+    }
+
+    /// Estimated size of the stream sending this snapshot, incrementally from `from` if given
+    pub fn send_size(&self, from: Option<impl AsRef<str>>) -> Result<u64> {
+        let size = lzc::send_space(self.name(), from, &SendOptions::default())?;
+        Ok(size)
+    }
+
+    /// User properties (module:property) set on or inherited by this snapshot
+    pub fn user_properties(&self) -> BTreeMap<String, property::PropertyValue<String>> {
+        property::user_properties(&self.dataset)
+    }
+
+    /// Set user property `property` (module:property) on this snapshot
+    pub fn set_user_property(
+        &mut self,
+        property: impl AsRef<str>,
+        value: impl AsRef<str>,
+    ) -> Result<()> {
+        property::set_user_property(&mut self.dataset, property.as_ref(), value.as_ref())
+    }
+
+    /// Clear user property `property` from this snapshot so that it is inherited again
+    pub fn inherit_user_property(&mut self, property: impl AsRef<str>) -> Result<()> {
+        property::inherit_user_property(&mut self.dataset, property.as_ref())
+    }
+
     #[inline]
     pub fn available(&self) -> u64 {
         self.dataset.numeric_property(ZFS_PROP_AVAILABLE)
@@ -98,26 +190,140 @@ impl Snapshot {
     pub fn objsetid(&self) -> u64 {
         self.dataset.numeric_property(ZFS_PROP_OBJSETID)
     }
+
+    #[inline]
+    pub fn devices(&self) -> property::OnOff {
+        self.dataset.numeric_property(ZFS_PROP_DEVICES).into()
+    }
+
+    #[inline]
+    pub fn exec(&self) -> property::OnOff {
+        self.dataset.numeric_property(ZFS_PROP_EXEC).into()
+    }
+
+    #[inline]
+    pub fn setuid(&self) -> property::OnOff {
+        self.dataset.numeric_property(ZFS_PROP_SETUID).into()
+    }
+
+    #[inline]
+    pub fn xattr(&self) -> property::Xattr {
+        self.dataset.numeric_property(ZFS_PROP_XATTR).into()
+    }
+
+    #[inline]
+    pub fn acltype(&self) -> property::AclType {
+        self.dataset.numeric_property(ZFS_PROP_ACLTYPE).into()
+    }
+
+    #[inline]
+    pub fn casesensitivity(&self) -> property::CaseSensitivity {
+        self.dataset.numeric_property(ZFS_PROP_CASE).into()
+    }
+
+    #[inline]
+    pub fn normalization(&self) -> property::Normalization {
+        self.dataset.numeric_property(ZFS_PROP_NORMALIZE).into()
+    }
+
+    #[inline]
+    pub fn utf8only(&self) -> property::OnOff {
+        self.dataset.numeric_property(ZFS_PROP_UTF8ONLY).into()
+    }
+
+    #[inline]
+    pub fn primarycache(&self) -> property::Cache {
+        self.dataset.numeric_property(ZFS_PROP_PRIMARYCACHE).into()
+    }
+
+    #[inline]
+    pub fn secondarycache(&self) -> property::Cache {
+        self.dataset
+            .numeric_property(ZFS_PROP_SECONDARYCACHE)
+            .into()
+    }
+
+    #[inline]
+    pub fn version(&self) -> u64 {
+        self.dataset.numeric_property(ZFS_PROP_VERSION)
+    }
+
+    #[inline]
+    pub fn written(&self) -> u64 {
+        self.dataset.numeric_property(ZFS_PROP_WRITTEN)
+    }
+
+    #[inline]
+    pub fn refcompressratio(&self) -> u64 {
+        self.dataset.numeric_property(ZFS_PROP_REFRATIO)
+    }
+}
+
+/// Snapshots are read-only, only caching and user properties can be changed
+#[derive(Debug)]
+pub struct SnapshotPropSetter<'a> {
+    snapshot: &'a mut Snapshot,
+    props: Properties,
+}
+
+impl<'a> SnapshotPropSetter<'a> {
+    pub fn new(snapshot: &'a mut Snapshot) -> Self {
+        Self {
+            snapshot,
+            props: Properties::new(),
+        }
+    }
+
+    pub fn primarycache(mut self, value: impl Into<property::Cache>) -> Self {
+        self.props.primarycache(value);
+        self
+    }
+
+    pub fn secondarycache(mut self, value: impl Into<property::Cache>) -> Self {
+        self.props.secondarycache(value);
+        self
+    }
+
+    pub fn commit(self) -> Result<()> {
+        self.snapshot.dataset.set_properties(self.props)?;
+        Ok(())
+    }
 }
 
 #[derive(Debug)]
 pub struct SnapshotBuilder {
     props: Result<nvpair::NvList>,
     recursive: bool,
+    datasets: Vec<String>,
 }
 
 impl SnapshotBuilder {
     pub fn new() -> Self {
         let props = Ok(nvpair::NvList::new());
         let recursive = false;
-        Self { props, recursive }
+        let datasets = Vec::new();
+        Self {
+            props,
+            recursive,
+            datasets,
+        }
     }
 
+    /// Create snapshot `name` (dataset@snapshot) along with the same named snapshot
+    /// of every additional dataset, atomically
     pub fn create(self, name: impl AsRef<str>) -> Result<Snapshot> {
-        let _props = self.props?;
+        let props = self.props?;
         let name = name.as_ref();
         if let Some((dataset, snapshot)) = name.split_once('@') {
-            snapshots(dataset, snapshot, self.recursive)?;
+            let mut datasets = vec![dataset.to_string()];
+            if self.recursive {
+                datasets.extend(descendants(dataset));
+            }
+            datasets.extend(self.datasets);
+            let snapshots = datasets
+                .into_iter()
+                .map(|dataset| format!("{dataset}@{snapshot}"));
+            lzc::create_snapshots(snapshots, props)?;
         } else {
             Err(DatasetError::invalid_snapshot_name(name))?;
         }
@@ -130,6 +336,36 @@ impl SnapshotBuilder {
             ..self
         }
     }
+
+    /// Also snapshot `dataset` (without its descendants)
+    pub fn dataset(mut self, dataset: impl AsRef<str>) -> Self {
+        self.datasets.push(dataset.as_ref().to_string());
+        self
+    }
+
+    /// Also snapshot each of the `datasets` (without their descendants)
+    pub fn datasets(mut self, datasets: impl IntoIterator<Item = impl AsRef<str>>) -> Self {
+        self.datasets.extend(
+            datasets
+                .into_iter()
+                .map(|dataset| dataset.as_ref().to_string()),
+        );
+        self
+    }
+
+    /// Set user property `property` on the created snapshots.
+    /// Snapshots accept only user properties (module:property)
+    pub fn property(mut self, property: impl AsRef<str>, value: impl AsRef<str>) -> Self {
+        let (property, value) = (property.as_ref(), value.as_ref());
+        self.props = match property::validate_user_property(property, value) {
+            Ok(()) => self.props.and_then(|mut props| {
+                props.add_string(property, value)?;
+                Ok(props)
+            }),
+            Err(err) => Err(err.into()),
+        };
+        self
+    }
 }
 
 impl Default for SnapshotBuilder {
@@ -138,16 +374,95 @@ impl Default for SnapshotBuilder {
     }
 }
 
-fn snapshots(dataset: impl AsRef<str>, snapshot: impl AsRef<str>, recursive: bool) -> Result<()> {
-    let snapshot = snapshot.as_ref();
-    let snapshots = libzfs::zfs_list_from(dataset)
-        .filesystems()
-        .volumes()
-        .recursive(recursive)
-        .get_collection()
-        .into_iter()
-        .map(|dataset| format!("{}@{}", dataset.name(), snapshot));
+/// A set of snapshots to be handled together, e.g. pruned by a retention policy
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct SnapshotSet {
+    names: BTreeSet<String>,
+}
+
+impl SnapshotSet {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn insert(&mut self, snapshot: impl AsRef<str>) -> bool {
+        self.names.insert(snapshot.as_ref().to_string())
+    }
+
+    pub fn contains(&self, snapshot: impl AsRef<str>) -> bool {
+        self.names.contains(snapshot.as_ref())
+    }
 
-    lzc::create_snapshots(snapshots, None)?;
+    pub fn len(&self) -> usize {
+        self.names.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.names.is_empty()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &str> {
+        self.names.iter().map(String::as_str)
+    }
+
+    /// Destroy all the snapshots in the set atomically in one transaction group.
+    /// With `defer` set, held or cloned snapshots are destroyed once released.
+    pub fn destroy(self, defer: bool) -> Result<()> {
+        if !self.is_empty() {
+            lzc::destroy_snapshots(self.names, defer)?;
+        }
+        Ok(())
+    }
+}
+
+impl FromIterator<String> for SnapshotSet {
+    fn from_iter<I: IntoIterator<Item = String>>(iter: I) -> Self {
+        let names = iter.into_iter().collect();
+        Self { names }
+    }
+}
+
+impl<'a> FromIterator<&'a str> for SnapshotSet {
+    fn from_iter<I: IntoIterator<Item = &'a str>>(iter: I) -> Self {
+        iter.into_iter().map(ToString::to_string).collect()
+    }
+}
+
+impl FromIterator<Snapshot> for SnapshotSet {
+    fn from_iter<I: IntoIterator<Item = Snapshot>>(iter: I) -> Self {
+        iter.into_iter().map(|snapshot| snapshot.name()).collect()
+    }
+}
+
+impl Extend<String> for SnapshotSet {
+    fn extend<I: IntoIterator<Item = String>>(&mut self, iter: I) {
+        self.names.extend(iter)
+    }
+}
+
+impl IntoIterator for SnapshotSet {
+    type Item = String;
+    type IntoIter = std::collections::btree_set::IntoIter<String>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.names.into_iter()
+    }
+}
+
+fn skipped_to_result(skipped: Vec<(String, lzc::LzcError)>) -> Result<()> {
+    if let Some((_, err)) = skipped.into_iter().next() {
+        Err(err)?;
+    }
     Ok(())
 }
+
+fn descendants(dataset: impl AsRef<str>) -> impl Iterator<Item = String> {
+    libzfs::zfs_list_from(dataset)
+        .filesystems()
+        .volumes()
+        .recursive(true)
+        .iter()
+        // The walk starts with the dataset itself
+        .skip(1)
+        .map(|dataset| dataset.name().to_string())
+}
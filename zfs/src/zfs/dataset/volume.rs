@@ -1,4 +1,6 @@
+use std::collections::BTreeMap;
 use std::ffi::CString;
+use std::str;
 
 use serde::ser::{Serialize, SerializeStruct, Serializer};
 
@@ -41,6 +43,40 @@ impl Volume {
         Ok(Self { dataset })
     }
 
+    /// Value of `property` along with where it comes from (local, inherited, default...)
+    pub fn property_with_source<'a, T: str::FromStr>(
+        &self,
+        property: impl Property<'a>,
+    ) -> Result<PropertyValue<T>> {
+        PropertyValue::get(&self.dataset, property)
+    }
+
+    /// Clear the local value of `property` so that it is inherited from the parent again,
+    /// or, with `received`, revert it to the value received with the last `zfs receive`
+    pub fn inherit<'a>(&mut self, property: impl Property<'a>, received: bool) -> Result<()> {
+        self.dataset.inherit_property(property.name(), received)?;
+        Ok(())
+    }
+
+    /// User properties (module:property) set on or inherited by this volume
+    pub fn user_properties(&self) -> BTreeMap<String, PropertyValue<String>> {
+        property::user_properties(&self.dataset)
+    }
+
+    /// Set user property `property` (module:property) on this volume
+    pub fn set_user_property(
+        &mut self,
+        property: impl AsRef<str>,
+        value: impl AsRef<str>,
+    ) -> Result<()> {
+        property::set_user_property(&mut self.dataset, property.as_ref(), value.as_ref())
+    }
+
+    /// Clear user property `property` from this volume so that it is inherited again
+    pub fn inherit_user_property(&mut self, property: impl AsRef<str>) -> Result<()> {
+        property::inherit_user_property(&mut self.dataset, property.as_ref())
+    }
+
     #[inline]
     pub fn available(&self) -> u64 {
         self.dataset.numeric_property(ZFS_PROP_AVAILABLE)
@@ -115,6 +151,104 @@ impl Volume {
     pub fn objsetid(&self) -> u64 {
         self.dataset.numeric_property(ZFS_PROP_OBJSETID)
     }
+
+    #[inline]
+    pub fn reservation(&self) -> property::Size {
+        self.dataset.numeric_property(ZFS_PROP_RESERVATION).into()
+    }
+
+    #[inline]
+    pub fn refreservation(&self) -> property::Size {
+        self.dataset
+            .numeric_property(ZFS_PROP_REFRESERVATION)
+            .into()
+    }
+
+    #[inline]
+    pub fn sync(&self) -> property::SyncMode {
+        self.dataset.numeric_property(ZFS_PROP_SYNC).into()
+    }
+
+    #[inline]
+    pub fn logbias(&self) -> property::LogBias {
+        self.dataset.numeric_property(ZFS_PROP_LOGBIAS).into()
+    }
+
+    #[inline]
+    pub fn primarycache(&self) -> property::Cache {
+        self.dataset.numeric_property(ZFS_PROP_PRIMARYCACHE).into()
+    }
+
+    #[inline]
+    pub fn secondarycache(&self) -> property::Cache {
+        self.dataset
+            .numeric_property(ZFS_PROP_SECONDARYCACHE)
+            .into()
+    }
+
+    #[inline]
+    pub fn dedup(&self) -> property::Dedup {
+        self.dataset.numeric_property(ZFS_PROP_DEDUP).into()
+    }
+
+    #[inline]
+    pub fn copies(&self) -> u64 {
+        self.dataset.numeric_property(ZFS_PROP_COPIES)
+    }
+
+    #[inline]
+    pub fn snapdev(&self) -> property::SnapDir {
+        self.dataset.numeric_property(ZFS_PROP_SNAPDEV).into()
+    }
+
+    #[inline]
+    pub fn redundant_metadata(&self) -> property::RedundantMetadata {
+        self.dataset
+            .numeric_property(ZFS_PROP_REDUNDANT_METADATA)
+            .into()
+    }
+
+    /// Limit on the number of snapshots of this volume, `None` when there is no limit
+    #[inline]
+    pub fn snapshot_limit(&self) -> Option<u64> {
+        Some(self.dataset.numeric_property(ZFS_PROP_SNAPSHOT_LIMIT))
+            .filter(|limit| *limit != u64::MAX)
+    }
+
+    #[inline]
+    pub fn snapshot_count(&self) -> u64 {
+        self.dataset.numeric_property(ZFS_PROP_SNAPSHOT_COUNT)
+    }
+
+    #[inline]
+    pub fn usedbysnapshots(&self) -> u64 {
+        self.dataset.numeric_property(ZFS_PROP_USEDSNAP)
+    }
+
+    #[inline]
+    pub fn usedbydataset(&self) -> u64 {
+        self.dataset.numeric_property(ZFS_PROP_USEDDS)
+    }
+
+    #[inline]
+    pub fn usedbychildren(&self) -> u64 {
+        self.dataset.numeric_property(ZFS_PROP_USEDCHILD)
+    }
+
+    #[inline]
+    pub fn usedbyrefreservation(&self) -> u64 {
+        self.dataset.numeric_property(ZFS_PROP_USEDREFRESERV)
+    }
+
+    #[inline]
+    pub fn written(&self) -> u64 {
+        self.dataset.numeric_property(ZFS_PROP_WRITTEN)
+    }
+
+    #[inline]
+    pub fn refcompressratio(&self) -> u64 {
+        self.dataset.numeric_property(ZFS_PROP_REFRATIO)
+    }
 }
 
 impl Serialize for Volume {
@@ -122,7 +256,7 @@ impl Serialize for Volume {
     where
         S: Serializer,
     {
-        let mut state = serializer.serialize_struct("Volume", 16)?;
+        let mut state = serializer.serialize_struct("Volume", 25)?;
         state.serialize_field(&property::NAME, &self.name())?;
         state.serialize_field(&property::AVAILABLE, &self.available())?;
         state.serialize_field(&property::VOLSIZE, &self.volsize())?;
@@ -139,6 +273,15 @@ impl Serialize for Volume {
         state.serialize_field(&property::REFERENCED, &self.referenced())?;
         state.serialize_field(&property::LOGICALREFERENCED, &self.logicalreferenced())?;
         state.serialize_field(&property::OBJSETID, &self.objsetid())?;
+        state.serialize_field(&property::RESERVATION, &self.reservation())?;
+        state.serialize_field(&property::REFRESERVATION, &self.refreservation())?;
+        state.serialize_field(&property::SYNC, &self.sync())?;
+        state.serialize_field(&property::LOGBIAS, &self.logbias())?;
+        state.serialize_field(&property::PRIMARYCACHE, &self.primarycache())?;
+        state.serialize_field(&property::SECONDARYCACHE, &self.secondarycache())?;
+        state.serialize_field(&property::DEDUP, &self.dedup())?;
+        state.serialize_field(&property::COPIES, &self.copies())?;
+        state.serialize_field(&property::REDUNDANT_METADATA, &self.redundant_metadata())?;
 
         state.end()
     }
@@ -148,6 +291,7 @@ impl Serialize for Volume {
 pub struct VolumeBuilder {
     props: Properties,
     volblocksize: u64,
+    crypt: Option<crypto::Crypt>,
     err: Option<DatasetError>,
 }
 
@@ -159,6 +303,7 @@ impl VolumeBuilder {
         Self {
             props,
             volblocksize,
+            crypt: None,
             err: None,
         }
     }
@@ -184,7 +329,15 @@ impl VolumeBuilder {
         self.props.volsize(size);
         self.props.volblocksize(self.volblocksize);
 
-        lzc::create_volume(name, self.props.into_inner())?;
+        if let Some(crypt) = self.crypt {
+            let key = crypt.apply(&mut self.props)?;
+            // lzc takes the properties as is, so have libzfs validate them first
+            let props =
+                libzfs::zfs_valid_proplist(name, libzfs::zfs_type_t::ZFS_TYPE_VOLUME, self.props)?;
+            lzc::create_volume_with_key(name, props, key.as_bytes())?;
+        } else {
+            lzc::create_volume(name, self.props.into_inner())?;
+        }
 
         let dataset = libzfs::ZfsHandle::new(cname)?;
         let volume = Volume { dataset };
@@ -217,6 +370,77 @@ impl VolumeBuilder {
         self
     }
 
+    pub fn reservation(mut self, value: impl Into<property::Size>) -> Self {
+        self.props.reservation(value);
+        self
+    }
+
+    pub fn refreservation(mut self, value: impl Into<property::Size>) -> Self {
+        self.props.refreservation(value);
+        self
+    }
+
+    pub fn sync(mut self, value: impl Into<property::SyncMode>) -> Self {
+        self.props.sync(value);
+        self
+    }
+
+    pub fn logbias(mut self, value: impl Into<property::LogBias>) -> Self {
+        self.props.logbias(value);
+        self
+    }
+
+    pub fn primarycache(mut self, value: impl Into<property::Cache>) -> Self {
+        self.props.primarycache(value);
+        self
+    }
+
+    pub fn secondarycache(mut self, value: impl Into<property::Cache>) -> Self {
+        self.props.secondarycache(value);
+        self
+    }
+
+    pub fn dedup(mut self, value: impl Into<property::Dedup>) -> Self {
+        self.props.dedup(value);
+        self
+    }
+
+    pub fn copies(mut self, value: u64) -> Self {
+        self.props.copies(value);
+        self
+    }
+
+    pub fn redundant_metadata(mut self, value: impl Into<property::RedundantMetadata>) -> Self {
+        self.props.redundant_metadata(value);
+        self
+    }
+
+    pub fn snapdev(mut self, value: impl Into<property::SnapDir>) -> Self {
+        self.props.snapdev(value);
+        self
+    }
+
+    pub fn snapshot_limit(mut self, value: Option<u64>) -> Self {
+        self.props.snapshot_limit(value);
+        self
+    }
+
+    /// Create the volume as a new encryption root with its key from `source`
+    pub fn encryption(
+        mut self,
+        encryption: impl Into<property::Encryption>,
+        format: property::KeyFormat,
+        source: KeySource,
+    ) -> Self {
+        let encryption = encryption.into();
+        self.crypt = Some(crypto::Crypt {
+            encryption,
+            format,
+            source,
+        });
+        self
+    }
+
     pub fn property(mut self, property: &str, value: &str) -> Self {
         self.props.string_property(property, value);
         self
@@ -263,6 +487,61 @@ impl<'a> VolumePropSetter<'a> {
         self
     }
 
+    pub fn reservation(mut self, value: impl Into<property::Size>) -> Self {
+        self.props.reservation(value);
+        self
+    }
+
+    pub fn refreservation(mut self, value: impl Into<property::Size>) -> Self {
+        self.props.refreservation(value);
+        self
+    }
+
+    pub fn sync(mut self, value: impl Into<property::SyncMode>) -> Self {
+        self.props.sync(value);
+        self
+    }
+
+    pub fn logbias(mut self, value: impl Into<property::LogBias>) -> Self {
+        self.props.logbias(value);
+        self
+    }
+
+    pub fn primarycache(mut self, value: impl Into<property::Cache>) -> Self {
+        self.props.primarycache(value);
+        self
+    }
+
+    pub fn secondarycache(mut self, value: impl Into<property::Cache>) -> Self {
+        self.props.secondarycache(value);
+        self
+    }
+
+    pub fn dedup(mut self, value: impl Into<property::Dedup>) -> Self {
+        self.props.dedup(value);
+        self
+    }
+
+    pub fn copies(mut self, value: u64) -> Self {
+        self.props.copies(value);
+        self
+    }
+
+    pub fn redundant_metadata(mut self, value: impl Into<property::RedundantMetadata>) -> Self {
+        self.props.redundant_metadata(value);
+        self
+    }
+
+    pub fn snapdev(mut self, value: impl Into<property::SnapDir>) -> Self {
+        self.props.snapdev(value);
+        self
+    }
+
+    pub fn snapshot_limit(mut self, value: Option<u64>) -> Self {
+        self.props.snapshot_limit(value);
+        self
+    }
+
     pub fn commit(self) -> Result<()> {
         self.volume.dataset.set_properties(self.props)?;
         Ok(())
@@ -1,4 +1,5 @@
 use std::borrow::Cow;
+use std::fmt;
 
 use once_cell::sync::Lazy;
 
@@ -8,22 +9,67 @@ use super::*;
 
 pub use error::InvalidProperty;
 
+pub use aclinherit::AclInherit;
+pub use aclmode::AclMode;
+pub use acltype::AclType;
+pub use cache::Cache;
 pub use canmount::CanMount;
+pub use casesensitivity::CaseSensitivity;
 pub use checksum::CheckSum;
 pub use compression::Compression;
+pub use dedup::Dedup;
+pub use dnodesize::DnodeSize;
+pub use encryption::Encryption;
+pub use keyformat::KeyFormat;
+pub use keystatus::KeyStatus;
+pub use logbias::LogBias;
 pub use mountpoint::MountPoint;
+pub use normalization::Normalization;
 pub use onfoff::OnOff;
+pub use redundantmetadata::RedundantMetadata;
+pub use size::parse_size;
+pub use size::Size;
+pub use snapdir::SnapDir;
+pub use sync::SyncMode;
+pub use user::is_user_property;
+pub use value::PropertyValue;
+pub use value::Source;
 pub use volmode::VolMode;
+pub use xattr::Xattr;
 pub use yesno::YesNo;
 
+pub(crate) use user::inherit_user_property;
+pub(crate) use user::set_user_property;
+pub(crate) use user::user_properties;
+pub(crate) use user::validate_user_property;
+
 mod error;
 
+mod aclinherit;
+mod aclmode;
+mod acltype;
+mod cache;
 mod canmount;
+mod casesensitivity;
 mod checksum;
 mod compression;
+mod dedup;
+mod dnodesize;
+mod encryption;
+mod keyformat;
+mod keystatus;
+mod logbias;
 mod mountpoint;
+mod normalization;
 mod onfoff;
+mod redundantmetadata;
+mod size;
+mod snapdir;
+mod sync;
+mod user;
+mod value;
 mod volmode;
+mod xattr;
 mod yesno;
 
 type PropName = Lazy<Cow<'static, str>>;
@@ -69,6 +115,49 @@ pub static RELATIME: PropName = Lazy::new(|| prop_name(ZFS_PROP_RELATIME));
 pub static VSCAN: PropName = Lazy::new(|| prop_name(ZFS_PROP_VSCAN));
 pub static OVERLAY: PropName = Lazy::new(|| prop_name(ZFS_PROP_OVERLAY));
 
+pub static ENCRYPTION: PropName = Lazy::new(|| prop_name(ZFS_PROP_ENCRYPTION));
+pub static KEYLOCATION: PropName = Lazy::new(|| prop_name(ZFS_PROP_KEYLOCATION));
+pub static KEYFORMAT: PropName = Lazy::new(|| prop_name(ZFS_PROP_KEYFORMAT));
+pub static PBKDF2_SALT: PropName = Lazy::new(|| prop_name(ZFS_PROP_PBKDF2_SALT));
+pub static PBKDF2_ITERS: PropName = Lazy::new(|| prop_name(ZFS_PROP_PBKDF2_ITERS));
+pub static ENCRYPTION_ROOT: PropName = Lazy::new(|| prop_name(ZFS_PROP_ENCRYPTION_ROOT));
+pub static KEYSTATUS: PropName = Lazy::new(|| prop_name(ZFS_PROP_KEYSTATUS));
+pub static IVSETGUID: PropName = Lazy::new(|| prop_name(ZFS_PROP_IVSET_GUID));
+pub static REDACT_SNAPS: PropName = Lazy::new(|| prop_name(ZFS_PROP_REDACT_SNAPS));
+pub static REDACT_COMPLETE: PropName = Lazy::new(|| prop_name(ZFS_PROP_REDACT_COMPLETE));
+
+pub static REFQUOTA: PropName = Lazy::new(|| prop_name(ZFS_PROP_REFQUOTA));
+pub static SPECIAL_SMALL_BLOCKS: PropName = Lazy::new(|| prop_name(ZFS_PROP_SPECIAL_SMALL_BLOCKS));
+pub static SYNC: PropName = Lazy::new(|| prop_name(ZFS_PROP_SYNC));
+pub static XATTR: PropName = Lazy::new(|| prop_name(ZFS_PROP_XATTR));
+pub static ACLTYPE: PropName = Lazy::new(|| prop_name(ZFS_PROP_ACLTYPE));
+pub static ACLINHERIT: PropName = Lazy::new(|| prop_name(ZFS_PROP_ACLINHERIT));
+pub static ACLMODE: PropName = Lazy::new(|| prop_name(ZFS_PROP_ACLMODE));
+pub static DNODESIZE: PropName = Lazy::new(|| prop_name(ZFS_PROP_DNODESIZE));
+pub static LOGBIAS: PropName = Lazy::new(|| prop_name(ZFS_PROP_LOGBIAS));
+pub static PRIMARYCACHE: PropName = Lazy::new(|| prop_name(ZFS_PROP_PRIMARYCACHE));
+pub static SECONDARYCACHE: PropName = Lazy::new(|| prop_name(ZFS_PROP_SECONDARYCACHE));
+pub static DEDUP: PropName = Lazy::new(|| prop_name(ZFS_PROP_DEDUP));
+pub static COPIES: PropName = Lazy::new(|| prop_name(ZFS_PROP_COPIES));
+pub static SNAPDIR: PropName = Lazy::new(|| prop_name(ZFS_PROP_SNAPDIR));
+pub static SNAPDEV: PropName = Lazy::new(|| prop_name(ZFS_PROP_SNAPDEV));
+pub static REDUNDANT_METADATA: PropName = Lazy::new(|| prop_name(ZFS_PROP_REDUNDANT_METADATA));
+pub static CASESENSITIVITY: PropName = Lazy::new(|| prop_name(ZFS_PROP_CASE));
+pub static NORMALIZATION: PropName = Lazy::new(|| prop_name(ZFS_PROP_NORMALIZE));
+pub static UTF8ONLY: PropName = Lazy::new(|| prop_name(ZFS_PROP_UTF8ONLY));
+pub static SHARESMB: PropName = Lazy::new(|| prop_name(ZFS_PROP_SHARESMB));
+pub static VERSION: PropName = Lazy::new(|| prop_name(ZFS_PROP_VERSION));
+pub static USEDSNAP: PropName = Lazy::new(|| prop_name(ZFS_PROP_USEDSNAP));
+pub static USEDDS: PropName = Lazy::new(|| prop_name(ZFS_PROP_USEDDS));
+pub static USEDCHILD: PropName = Lazy::new(|| prop_name(ZFS_PROP_USEDCHILD));
+pub static USEDREFRESERV: PropName = Lazy::new(|| prop_name(ZFS_PROP_USEDREFRESERV));
+pub static WRITTEN: PropName = Lazy::new(|| prop_name(ZFS_PROP_WRITTEN));
+pub static REFRATIO: PropName = Lazy::new(|| prop_name(ZFS_PROP_REFRATIO));
+pub static FILESYSTEM_LIMIT: PropName = Lazy::new(|| prop_name(ZFS_PROP_FILESYSTEM_LIMIT));
+pub static SNAPSHOT_LIMIT: PropName = Lazy::new(|| prop_name(ZFS_PROP_SNAPSHOT_LIMIT));
+pub static FILESYSTEM_COUNT: PropName = Lazy::new(|| prop_name(ZFS_PROP_FILESYSTEM_COUNT));
+pub static SNAPSHOT_COUNT: PropName = Lazy::new(|| prop_name(ZFS_PROP_SNAPSHOT_COUNT));
+
 #[inline]
 pub fn prop_name(prop: libzfs::zfs_prop_t) -> Cow<'static, str> {
     libzfs::zfs_prop_to_name(prop)
@@ -153,6 +242,177 @@ impl Properties {
         self.set_numeric(ZFS_PROP_VOLMODE, volmode.into());
     }
 
+    pub fn encryption(&mut self, encryption: impl Into<Encryption>) {
+        self.set_numeric(ZFS_PROP_ENCRYPTION, encryption.into());
+    }
+
+    pub fn keyformat(&mut self, keyformat: impl Into<KeyFormat>) {
+        self.set_numeric(ZFS_PROP_KEYFORMAT, keyformat.into());
+    }
+
+    pub fn keylocation(&mut self, keylocation: impl AsRef<str>) {
+        self.set_string(ZFS_PROP_KEYLOCATION, keylocation.as_ref());
+    }
+
+    pub fn pbkdf2salt(&mut self, salt: u64) {
+        self.set_numeric(ZFS_PROP_PBKDF2_SALT, salt);
+    }
+
+    pub fn pbkdf2iters(&mut self, iters: u64) {
+        self.set_numeric(ZFS_PROP_PBKDF2_ITERS, iters);
+    }
+
+    pub fn quota(&mut self, quota: impl Into<Size>) {
+        self.set_numeric(ZFS_PROP_QUOTA, quota.into());
+    }
+
+    pub fn refquota(&mut self, refquota: impl Into<Size>) {
+        self.set_numeric(ZFS_PROP_REFQUOTA, refquota.into());
+    }
+
+    pub fn reservation(&mut self, reservation: impl Into<Size>) {
+        self.set_numeric(ZFS_PROP_RESERVATION, reservation.into());
+    }
+
+    pub fn refreservation(&mut self, refreservation: impl Into<Size>) {
+        self.set_numeric(ZFS_PROP_REFRESERVATION, refreservation.into());
+    }
+
+    pub fn recordsize(&mut self, recordsize: u64) {
+        self.set_numeric(ZFS_PROP_RECORDSIZE, recordsize);
+    }
+
+    pub fn special_small_blocks(&mut self, size: u64) {
+        self.set_numeric(ZFS_PROP_SPECIAL_SMALL_BLOCKS, size);
+    }
+
+    pub fn sync(&mut self, sync: impl Into<SyncMode>) {
+        self.set_numeric(ZFS_PROP_SYNC, sync.into());
+    }
+
+    pub fn xattr(&mut self, xattr: impl Into<Xattr>) {
+        self.set_numeric(ZFS_PROP_XATTR, xattr.into());
+    }
+
+    pub fn acltype(&mut self, acltype: impl Into<AclType>) {
+        self.set_string(ZFS_PROP_ACLTYPE, acltype.into());
+    }
+
+    pub fn aclinherit(&mut self, aclinherit: impl Into<AclInherit>) {
+        self.set_numeric(ZFS_PROP_ACLINHERIT, aclinherit.into());
+    }
+
+    pub fn aclmode(&mut self, aclmode: impl Into<AclMode>) {
+        self.set_numeric(ZFS_PROP_ACLMODE, aclmode.into());
+    }
+
+    pub fn dnodesize(&mut self, dnodesize: impl Into<DnodeSize>) {
+        self.set_numeric(ZFS_PROP_DNODESIZE, dnodesize.into());
+    }
+
+    pub fn logbias(&mut self, logbias: impl Into<LogBias>) {
+        self.set_numeric(ZFS_PROP_LOGBIAS, logbias.into());
+    }
+
+    pub fn primarycache(&mut self, cache: impl Into<Cache>) {
+        self.set_numeric(ZFS_PROP_PRIMARYCACHE, cache.into());
+    }
+
+    pub fn secondarycache(&mut self, cache: impl Into<Cache>) {
+        self.set_numeric(ZFS_PROP_SECONDARYCACHE, cache.into());
+    }
+
+    pub fn dedup(&mut self, dedup: impl Into<Dedup>) {
+        self.set_string(ZFS_PROP_DEDUP, dedup.into());
+    }
+
+    pub fn copies(&mut self, copies: u64) {
+        self.set_numeric(ZFS_PROP_COPIES, copies);
+    }
+
+    pub fn snapdir(&mut self, snapdir: impl Into<SnapDir>) {
+        self.set_numeric(ZFS_PROP_SNAPDIR, snapdir.into());
+    }
+
+    pub fn snapdev(&mut self, snapdev: impl Into<SnapDir>) {
+        self.set_numeric(ZFS_PROP_SNAPDEV, snapdev.into());
+    }
+
+    pub fn redundant_metadata(&mut self, redundant_metadata: impl Into<RedundantMetadata>) {
+        self.set_numeric(ZFS_PROP_REDUNDANT_METADATA, redundant_metadata.into());
+    }
+
+    pub fn casesensitivity(&mut self, casesensitivity: impl Into<CaseSensitivity>) {
+        self.set_numeric(ZFS_PROP_CASE, casesensitivity.into());
+    }
+
+    pub fn normalization(&mut self, normalization: impl Into<Normalization>) {
+        self.set_numeric(ZFS_PROP_NORMALIZE, normalization.into());
+    }
+
+    pub fn utf8only(&mut self, utf8only: impl Into<OnOff>) {
+        self.set_numeric(ZFS_PROP_UTF8ONLY, utf8only.into());
+    }
+
+    pub fn sharenfs(&mut self, sharenfs: impl AsRef<str>) {
+        self.set_string(ZFS_PROP_SHARENFS, sharenfs.as_ref());
+    }
+
+    pub fn sharesmb(&mut self, sharesmb: impl AsRef<str>) {
+        self.set_string(ZFS_PROP_SHARESMB, sharesmb.as_ref());
+    }
+
+    /// Limit on the number of filesystems under this one, `None` for no limit
+    pub fn filesystem_limit(&mut self, limit: Option<u64>) {
+        self.set_numeric(ZFS_PROP_FILESYSTEM_LIMIT, limit.unwrap_or(u64::MAX));
+    }
+
+    /// Limit on the number of snapshots under this dataset, `None` for no limit
+    pub fn snapshot_limit(&mut self, limit: Option<u64>) {
+        self.set_numeric(ZFS_PROP_SNAPSHOT_LIMIT, limit.unwrap_or(u64::MAX));
+    }
+
+    /// Quota on the space used by user `user`, a name or a numeric id
+    pub fn userquota(&mut self, user: impl fmt::Display, quota: impl Into<Size>) {
+        self.set_numeric(format!("userquota@{user}"), quota.into());
+    }
+
+    /// Quota on the space used by group `group`, a name or a numeric id
+    pub fn groupquota(&mut self, group: impl fmt::Display, quota: impl Into<Size>) {
+        self.set_numeric(format!("groupquota@{group}"), quota.into());
+    }
+
+    /// Quota on the space used by project `project`, a name or a numeric id
+    pub fn projectquota(&mut self, project: impl fmt::Display, quota: impl Into<Size>) {
+        self.set_numeric(format!("projectquota@{project}"), quota.into());
+    }
+
+    /// Quota on the number of objects owned by user `user`, `None` for no quota
+    pub fn userobjquota(&mut self, user: impl fmt::Display, quota: Option<u64>) {
+        self.set_numeric(format!("userobjquota@{user}"), quota.unwrap_or(0));
+    }
+
+    /// Quota on the number of objects owned by group `group`, `None` for no quota
+    pub fn groupobjquota(&mut self, group: impl fmt::Display, quota: Option<u64>) {
+        self.set_numeric(format!("groupobjquota@{group}"), quota.unwrap_or(0));
+    }
+
+    /// Quota on the number of objects owned by project `project`, `None` for no quota
+    pub fn projectobjquota(&mut self, project: impl fmt::Display, quota: Option<u64>) {
+        self.set_numeric(format!("projectobjquota@{project}"), quota.unwrap_or(0));
+    }
+
+    /// Set user property `property` (module:property)
+    pub fn user_property(
+        &mut self,
+        property: impl AsRef<str>,
+        value: impl AsRef<str>,
+    ) -> Result<&mut Self, InvalidProperty> {
+        let (property, value) = (property.as_ref(), value.as_ref());
+        validate_user_property(property, value)?;
+        Ok(self.set_string(property, value))
+    }
+
     pub fn string_property<'a>(
         &mut self,
         property: impl Property<'a>,
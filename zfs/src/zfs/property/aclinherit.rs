@@ -0,0 +1,97 @@
+#![allow(clippy::use_self)]
+
+use std::fmt;
+use std::str;
+
+use serde::{Deserialize, Serialize};
+
+use super::*;
+
+// aclinherit=discard|noallow|restricted|passthrough|passthrough-x
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum AclInherit {
+    Discard,
+    NoAllow,
+    #[default]
+    Restricted,
+    Passthrough,
+    PassthroughX,
+}
+
+impl AclInherit {
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::Discard => "discard",
+            Self::NoAllow => "noallow",
+            Self::Restricted => "restricted",
+            Self::Passthrough => "passthrough",
+            Self::PassthroughX => "passthrough-x",
+        }
+    }
+}
+
+impl AsRef<str> for AclInherit {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for AclInherit {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.as_str().fmt(f)
+    }
+}
+
+impl str::FromStr for AclInherit {
+    type Err = error::InvalidProperty;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "discard" => Ok(Self::Discard),
+            "noallow" => Ok(Self::NoAllow),
+            "restricted" => Ok(Self::Restricted),
+            "passthrough" => Ok(Self::Passthrough),
+            "passthrough-x" => Ok(Self::PassthroughX),
+            "secure" => Ok(Self::Restricted),
+            other => Err(error::InvalidProperty::invalid_value(other)),
+        }
+    }
+}
+
+macro_rules! numeric {
+    ($numeric:ty) => {
+        impl From<$numeric> for AclInherit {
+            fn from(value: $numeric) -> Self {
+                match value {
+                    0 => Self::Discard,
+                    1 => Self::NoAllow,
+                    4 => Self::Restricted,
+                    3 => Self::Passthrough,
+                    5 => Self::PassthroughX,
+                    _ => Self::Restricted,
+                }
+            }
+        }
+
+        impl From<AclInherit> for $numeric {
+            fn from(value: AclInherit) -> Self {
+                match value {
+                    AclInherit::Discard => 0,
+                    AclInherit::NoAllow => 1,
+                    AclInherit::Restricted => 4,
+                    AclInherit::Passthrough => 3,
+                    AclInherit::PassthroughX => 5,
+                }
+            }
+        }
+    };
+}
+
+numeric!(i8);
+numeric!(i16);
+numeric!(i32);
+numeric!(i64);
+numeric!(u8);
+numeric!(u16);
+numeric!(u32);
+numeric!(u64);
@@ -0,0 +1,91 @@
+#![allow(clippy::use_self)]
+
+use std::fmt;
+use std::str;
+
+use serde::{Deserialize, Serialize};
+
+use super::*;
+
+// aclmode=discard|groupmask|passthrough|restricted
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum AclMode {
+    #[default]
+    Discard,
+    GroupMask,
+    Passthrough,
+    Restricted,
+}
+
+impl AclMode {
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::Discard => "discard",
+            Self::GroupMask => "groupmask",
+            Self::Passthrough => "passthrough",
+            Self::Restricted => "restricted",
+        }
+    }
+}
+
+impl AsRef<str> for AclMode {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for AclMode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.as_str().fmt(f)
+    }
+}
+
+impl str::FromStr for AclMode {
+    type Err = error::InvalidProperty;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "discard" => Ok(Self::Discard),
+            "groupmask" => Ok(Self::GroupMask),
+            "passthrough" => Ok(Self::Passthrough),
+            "restricted" => Ok(Self::Restricted),
+            other => Err(error::InvalidProperty::invalid_value(other)),
+        }
+    }
+}
+
+macro_rules! numeric {
+    ($numeric:ty) => {
+        impl From<$numeric> for AclMode {
+            fn from(value: $numeric) -> Self {
+                match value {
+                    0 => Self::Discard,
+                    2 => Self::GroupMask,
+                    3 => Self::Passthrough,
+                    4 => Self::Restricted,
+                    _ => Self::Discard,
+                }
+            }
+        }
+
+        impl From<AclMode> for $numeric {
+            fn from(value: AclMode) -> Self {
+                match value {
+                    AclMode::Discard => 0,
+                    AclMode::GroupMask => 2,
+                    AclMode::Passthrough => 3,
+                    AclMode::Restricted => 4,
+                }
+            }
+        }
+    };
+}
+
+numeric!(i8);
+numeric!(i16);
+numeric!(i32);
+numeric!(i64);
+numeric!(u8);
+numeric!(u16);
+numeric!(u32);
+numeric!(u64);
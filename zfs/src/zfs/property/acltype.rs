@@ -0,0 +1,88 @@
+#![allow(clippy::use_self)]
+
+use std::fmt;
+use std::str;
+
+use serde::{Deserialize, Serialize};
+
+use super::*;
+
+// acltype=off|nfsv4|posix (noacl and posixacl are aliases)
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum AclType {
+    #[default]
+    Off,
+    Nfsv4,
+    Posix,
+}
+
+impl AclType {
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::Off => "off",
+            Self::Nfsv4 => "nfsv4",
+            Self::Posix => "posix",
+        }
+    }
+}
+
+impl AsRef<str> for AclType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for AclType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.as_str().fmt(f)
+    }
+}
+
+impl str::FromStr for AclType {
+    type Err = error::InvalidProperty;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "off" => Ok(Self::Off),
+            "nfsv4" => Ok(Self::Nfsv4),
+            "posix" => Ok(Self::Posix),
+            "noacl" => Ok(Self::Off),
+            "posixacl" => Ok(Self::Posix),
+            other => Err(error::InvalidProperty::invalid_value(other)),
+        }
+    }
+}
+
+macro_rules! numeric {
+    ($numeric:ty) => {
+        impl From<$numeric> for AclType {
+            fn from(value: $numeric) -> Self {
+                match value {
+                    0 => Self::Off,
+                    2 => Self::Nfsv4,
+                    1 => Self::Posix,
+                    _ => Self::Off,
+                }
+            }
+        }
+
+        impl From<AclType> for $numeric {
+            fn from(value: AclType) -> Self {
+                match value {
+                    AclType::Off => 0,
+                    AclType::Nfsv4 => 2,
+                    AclType::Posix => 1,
+                }
+            }
+        }
+    };
+}
+
+numeric!(i8);
+numeric!(i16);
+numeric!(i32);
+numeric!(i64);
+numeric!(u8);
+numeric!(u16);
+numeric!(u32);
+numeric!(u64);
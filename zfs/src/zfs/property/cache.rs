@@ -0,0 +1,86 @@
+#![allow(clippy::use_self)]
+
+use std::fmt;
+use std::str;
+
+use serde::{Deserialize, Serialize};
+
+use super::*;
+
+// primarycache|secondarycache=all|none|metadata
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Cache {
+    #[default]
+    All,
+    None,
+    Metadata,
+}
+
+impl Cache {
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::All => "all",
+            Self::None => "none",
+            Self::Metadata => "metadata",
+        }
+    }
+}
+
+impl AsRef<str> for Cache {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for Cache {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.as_str().fmt(f)
+    }
+}
+
+impl str::FromStr for Cache {
+    type Err = error::InvalidProperty;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "all" => Ok(Self::All),
+            "none" => Ok(Self::None),
+            "metadata" => Ok(Self::Metadata),
+            other => Err(error::InvalidProperty::invalid_value(other)),
+        }
+    }
+}
+
+macro_rules! numeric {
+    ($numeric:ty) => {
+        impl From<$numeric> for Cache {
+            fn from(value: $numeric) -> Self {
+                match value {
+                    2 => Self::All,
+                    0 => Self::None,
+                    1 => Self::Metadata,
+                    _ => Self::All,
+                }
+            }
+        }
+
+        impl From<Cache> for $numeric {
+            fn from(value: Cache) -> Self {
+                match value {
+                    Cache::All => 2,
+                    Cache::None => 0,
+                    Cache::Metadata => 1,
+                }
+            }
+        }
+    };
+}
+
+numeric!(i8);
+numeric!(i16);
+numeric!(i32);
+numeric!(i64);
+numeric!(u8);
+numeric!(u16);
+numeric!(u32);
+numeric!(u64);
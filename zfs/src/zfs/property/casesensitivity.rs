@@ -0,0 +1,86 @@
+#![allow(clippy::use_self)]
+
+use std::fmt;
+use std::str;
+
+use serde::{Deserialize, Serialize};
+
+use super::*;
+
+// casesensitivity=sensitive|insensitive|mixed
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum CaseSensitivity {
+    #[default]
+    Sensitive,
+    Insensitive,
+    Mixed,
+}
+
+impl CaseSensitivity {
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::Sensitive => "sensitive",
+            Self::Insensitive => "insensitive",
+            Self::Mixed => "mixed",
+        }
+    }
+}
+
+impl AsRef<str> for CaseSensitivity {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for CaseSensitivity {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.as_str().fmt(f)
+    }
+}
+
+impl str::FromStr for CaseSensitivity {
+    type Err = error::InvalidProperty;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "sensitive" => Ok(Self::Sensitive),
+            "insensitive" => Ok(Self::Insensitive),
+            "mixed" => Ok(Self::Mixed),
+            other => Err(error::InvalidProperty::invalid_value(other)),
+        }
+    }
+}
+
+macro_rules! numeric {
+    ($numeric:ty) => {
+        impl From<$numeric> for CaseSensitivity {
+            fn from(value: $numeric) -> Self {
+                match value {
+                    0 => Self::Sensitive,
+                    1 => Self::Insensitive,
+                    2 => Self::Mixed,
+                    _ => Self::Sensitive,
+                }
+            }
+        }
+
+        impl From<CaseSensitivity> for $numeric {
+            fn from(value: CaseSensitivity) -> Self {
+                match value {
+                    CaseSensitivity::Sensitive => 0,
+                    CaseSensitivity::Insensitive => 1,
+                    CaseSensitivity::Mixed => 2,
+                }
+            }
+        }
+    };
+}
+
+numeric!(i8);
+numeric!(i16);
+numeric!(i32);
+numeric!(i64);
+numeric!(u8);
+numeric!(u16);
+numeric!(u32);
+numeric!(u64);
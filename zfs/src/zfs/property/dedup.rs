@@ -0,0 +1,130 @@
+#![allow(clippy::use_self)]
+
+use std::fmt;
+use std::str;
+
+use serde::{Deserialize, Serialize};
+
+use super::*;
+
+// dedup=off|on|verify|sha256[,verify]|sha512[,verify]|skein[,verify]|edonr,verify
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Dedup {
+    #[default]
+    Off,
+    On,
+    Verify,
+    Sha256,
+    Sha256Verify,
+    Sha512,
+    Sha512Verify,
+    Skein,
+    SkeinVerify,
+    EdonrVerify,
+}
+
+impl Dedup {
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::Off => "off",
+            Self::On => "on",
+            Self::Verify => "verify",
+            Self::Sha256 => "sha256",
+            Self::Sha256Verify => "sha256,verify",
+            Self::Sha512 => "sha512",
+            Self::Sha512Verify => "sha512,verify",
+            Self::Skein => "skein",
+            Self::SkeinVerify => "skein,verify",
+            Self::EdonrVerify => "edonr,verify",
+        }
+    }
+}
+
+impl AsRef<str> for Dedup {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for Dedup {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.as_str().fmt(f)
+    }
+}
+
+impl str::FromStr for Dedup {
+    type Err = error::InvalidProperty;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "off" => Ok(Self::Off),
+            "on" => Ok(Self::On),
+            "verify" => Ok(Self::Verify),
+            "sha256" => Ok(Self::Sha256),
+            "sha256,verify" => Ok(Self::Sha256Verify),
+            "sha512" => Ok(Self::Sha512),
+            "sha512,verify" => Ok(Self::Sha512Verify),
+            "skein" => Ok(Self::Skein),
+            "skein,verify" => Ok(Self::SkeinVerify),
+            "edonr,verify" => Ok(Self::EdonrVerify),
+            other => Err(error::InvalidProperty::invalid_value(other)),
+        }
+    }
+}
+
+impl From<bool> for Dedup {
+    fn from(v: bool) -> Self {
+        if v {
+            Self::On
+        } else {
+            Self::Off
+        }
+    }
+}
+
+// checksum function, with ZIO_CHECKSUM_VERIFY (0x100) for the verifying variants
+macro_rules! numeric {
+    ($numeric:ty) => {
+        impl From<$numeric> for Dedup {
+            fn from(value: $numeric) -> Self {
+                match value {
+                    2 => Self::Off,
+                    1 => Self::On,
+                    0x101 => Self::Verify,
+                    8 => Self::Sha256,
+                    0x108 => Self::Sha256Verify,
+                    11 => Self::Sha512,
+                    0x10b => Self::Sha512Verify,
+                    12 => Self::Skein,
+                    0x10c => Self::SkeinVerify,
+                    0x10d => Self::EdonrVerify,
+                    _ => Self::Off,
+                }
+            }
+        }
+
+        impl From<Dedup> for $numeric {
+            fn from(value: Dedup) -> Self {
+                match value {
+                    Dedup::Off => 2,
+                    Dedup::On => 1,
+                    Dedup::Verify => 0x101,
+                    Dedup::Sha256 => 8,
+                    Dedup::Sha256Verify => 0x108,
+                    Dedup::Sha512 => 11,
+                    Dedup::Sha512Verify => 0x10b,
+                    Dedup::Skein => 12,
+                    Dedup::SkeinVerify => 0x10c,
+                    Dedup::EdonrVerify => 0x10d,
+                }
+            }
+        }
+    };
+}
+
+numeric!(i16);
+numeric!(i32);
+numeric!(i64);
+numeric!(u16);
+numeric!(u32);
+numeric!(u64);
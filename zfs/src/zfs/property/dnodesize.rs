@@ -0,0 +1,104 @@
+#![allow(clippy::use_self)]
+
+use std::fmt;
+use std::str;
+
+use serde::{Deserialize, Serialize};
+
+use super::*;
+
+// dnodesize=legacy|auto|1k|2k|4k|8k|16k
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum DnodeSize {
+    #[default]
+    Legacy,
+    Auto,
+    Size1K,
+    Size2K,
+    Size4K,
+    Size8K,
+    Size16K,
+}
+
+impl DnodeSize {
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::Legacy => "legacy",
+            Self::Auto => "auto",
+            Self::Size1K => "1k",
+            Self::Size2K => "2k",
+            Self::Size4K => "4k",
+            Self::Size8K => "8k",
+            Self::Size16K => "16k",
+        }
+    }
+}
+
+impl AsRef<str> for DnodeSize {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for DnodeSize {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.as_str().fmt(f)
+    }
+}
+
+impl str::FromStr for DnodeSize {
+    type Err = error::InvalidProperty;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "legacy" => Ok(Self::Legacy),
+            "auto" => Ok(Self::Auto),
+            "1k" => Ok(Self::Size1K),
+            "2k" => Ok(Self::Size2K),
+            "4k" => Ok(Self::Size4K),
+            "8k" => Ok(Self::Size8K),
+            "16k" => Ok(Self::Size16K),
+            other => Err(error::InvalidProperty::invalid_value(other)),
+        }
+    }
+}
+
+macro_rules! numeric {
+    ($numeric:ty) => {
+        impl From<$numeric> for DnodeSize {
+            fn from(value: $numeric) -> Self {
+                match value {
+                    0 => Self::Legacy,
+                    1 => Self::Auto,
+                    0x400 => Self::Size1K,
+                    0x800 => Self::Size2K,
+                    0x1000 => Self::Size4K,
+                    0x2000 => Self::Size8K,
+                    0x4000 => Self::Size16K,
+                    _ => Self::Legacy,
+                }
+            }
+        }
+
+        impl From<DnodeSize> for $numeric {
+            fn from(value: DnodeSize) -> Self {
+                match value {
+                    DnodeSize::Legacy => 0,
+                    DnodeSize::Auto => 1,
+                    DnodeSize::Size1K => 0x400,
+                    DnodeSize::Size2K => 0x800,
+                    DnodeSize::Size4K => 0x1000,
+                    DnodeSize::Size8K => 0x2000,
+                    DnodeSize::Size16K => 0x4000,
+                }
+            }
+        }
+    };
+}
+
+numeric!(i16);
+numeric!(i32);
+numeric!(i64);
+numeric!(u16);
+numeric!(u32);
+numeric!(u64);
@@ -0,0 +1,121 @@
+#![allow(clippy::use_self)]
+
+use std::fmt;
+use std::str;
+
+use serde::{Deserialize, Serialize};
+
+use super::*;
+
+// encryption=off|on|aes-128-ccm|aes-192-ccm|aes-256-ccm|aes-128-gcm|aes-192-gcm|aes-256-gcm
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Encryption {
+    #[default]
+    Off,
+    On,
+    Aes128Ccm,
+    Aes192Ccm,
+    Aes256Ccm,
+    Aes128Gcm,
+    Aes192Gcm,
+    Aes256Gcm,
+}
+
+impl Encryption {
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::Off => "off",
+            Self::On => "on",
+            Self::Aes128Ccm => "aes-128-ccm",
+            Self::Aes192Ccm => "aes-192-ccm",
+            Self::Aes256Ccm => "aes-256-ccm",
+            Self::Aes128Gcm => "aes-128-gcm",
+            Self::Aes192Gcm => "aes-192-gcm",
+            Self::Aes256Gcm => "aes-256-gcm",
+        }
+    }
+}
+
+impl fmt::Display for Encryption {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.as_str().fmt(f)
+    }
+}
+
+impl AsRef<str> for Encryption {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl str::FromStr for Encryption {
+    type Err = error::InvalidProperty;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "off" => Ok(Self::Off),
+            "on" => Ok(Self::On),
+            "aes-128-ccm" => Ok(Self::Aes128Ccm),
+            "aes-192-ccm" => Ok(Self::Aes192Ccm),
+            "aes-256-ccm" => Ok(Self::Aes256Ccm),
+            "aes-128-gcm" => Ok(Self::Aes128Gcm),
+            "aes-192-gcm" => Ok(Self::Aes192Gcm),
+            "aes-256-gcm" => Ok(Self::Aes256Gcm),
+            other => Err(error::InvalidProperty::invalid_value(other)),
+        }
+    }
+}
+
+impl From<bool> for Encryption {
+    fn from(v: bool) -> Self {
+        if v {
+            Self::On
+        } else {
+            Self::Off
+        }
+    }
+}
+
+macro_rules! numeric {
+    ($numeric:ty) => {
+        impl From<$numeric> for Encryption {
+            fn from(value: $numeric) -> Self {
+                match value {
+                    2 => Self::Off,
+                    1 => Self::On,
+                    3 => Self::Aes128Ccm,
+                    4 => Self::Aes192Ccm,
+                    5 => Self::Aes256Ccm,
+                    6 => Self::Aes128Gcm,
+                    7 => Self::Aes192Gcm,
+                    8 => Self::Aes256Gcm,
+                    _ => Self::Off,
+                }
+            }
+        }
+
+        impl From<Encryption> for $numeric {
+            fn from(value: Encryption) -> Self {
+                match value {
+                    Encryption::Off => 2,
+                    Encryption::On => 1,
+                    Encryption::Aes128Ccm => 3,
+                    Encryption::Aes192Ccm => 4,
+                    Encryption::Aes256Ccm => 5,
+                    Encryption::Aes128Gcm => 6,
+                    Encryption::Aes192Gcm => 7,
+                    Encryption::Aes256Gcm => 8,
+                }
+            }
+        }
+    };
+}
+
+numeric!(i8);
+numeric!(i16);
+numeric!(i32);
+numeric!(i64);
+numeric!(u8);
+numeric!(u16);
+numeric!(u32);
+numeric!(u64);
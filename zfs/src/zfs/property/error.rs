@@ -6,14 +6,26 @@ pub enum InvalidProperty {
     NoSuchProperty(String),
     #[error("Invalid value ({0})")]
     InvalidValue(String),
+    #[error("Not a user property ({0})")]
+    NotUserProperty(String),
+    #[error("Too long ({0})")]
+    TooLong(String),
 }
 
 impl InvalidProperty {
-    pub(crate) fn _no_such_property(prop: impl ToString) -> Self {
+    pub(crate) fn no_such_property(prop: impl ToString) -> Self {
         Self::NoSuchProperty(prop.to_string())
     }
 
     pub(crate) fn invalid_value(value: impl ToString) -> Self {
         Self::InvalidValue(value.to_string())
     }
+
+    pub(crate) fn not_user_property(prop: impl ToString) -> Self {
+        Self::NotUserProperty(prop.to_string())
+    }
+
+    pub(crate) fn too_long(what: impl ToString) -> Self {
+        Self::TooLong(what.to_string())
+    }
 }
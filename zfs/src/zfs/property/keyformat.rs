@@ -0,0 +1,91 @@
+#![allow(clippy::use_self)]
+
+use std::fmt;
+use std::str;
+
+use serde::{Deserialize, Serialize};
+
+use super::*;
+
+// keyformat=none|raw|hex|passphrase
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum KeyFormat {
+    #[default]
+    None,
+    Raw,
+    Hex,
+    Passphrase,
+}
+
+impl KeyFormat {
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::None => "none",
+            Self::Raw => "raw",
+            Self::Hex => "hex",
+            Self::Passphrase => "passphrase",
+        }
+    }
+}
+
+impl fmt::Display for KeyFormat {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.as_str().fmt(f)
+    }
+}
+
+impl AsRef<str> for KeyFormat {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl str::FromStr for KeyFormat {
+    type Err = error::InvalidProperty;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "none" => Ok(Self::None),
+            "raw" => Ok(Self::Raw),
+            "hex" => Ok(Self::Hex),
+            "passphrase" => Ok(Self::Passphrase),
+            other => Err(error::InvalidProperty::invalid_value(other)),
+        }
+    }
+}
+
+macro_rules! numeric {
+    ($numeric:ty) => {
+        impl From<$numeric> for KeyFormat {
+            fn from(value: $numeric) -> Self {
+                match value {
+                    0 => Self::None,
+                    1 => Self::Raw,
+                    2 => Self::Hex,
+                    3 => Self::Passphrase,
+                    _ => Self::None,
+                }
+            }
+        }
+
+        impl From<KeyFormat> for $numeric {
+            fn from(value: KeyFormat) -> Self {
+                match value {
+                    KeyFormat::None => 0,
+                    KeyFormat::Raw => 1,
+                    KeyFormat::Hex => 2,
+                    KeyFormat::Passphrase => 3,
+                }
+            }
+        }
+    };
+}
+
+numeric!(i8);
+numeric!(i16);
+numeric!(i32);
+numeric!(i64);
+numeric!(u8);
+numeric!(u16);
+numeric!(u32);
+numeric!(u64);
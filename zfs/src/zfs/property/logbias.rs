@@ -0,0 +1,81 @@
+#![allow(clippy::use_self)]
+
+use std::fmt;
+use std::str;
+
+use serde::{Deserialize, Serialize};
+
+use super::*;
+
+// logbias=latency|throughput
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum LogBias {
+    #[default]
+    Latency,
+    Throughput,
+}
+
+impl LogBias {
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::Latency => "latency",
+            Self::Throughput => "throughput",
+        }
+    }
+}
+
+impl AsRef<str> for LogBias {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for LogBias {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.as_str().fmt(f)
+    }
+}
+
+impl str::FromStr for LogBias {
+    type Err = error::InvalidProperty;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "latency" => Ok(Self::Latency),
+            "throughput" => Ok(Self::Throughput),
+            other => Err(error::InvalidProperty::invalid_value(other)),
+        }
+    }
+}
+
+macro_rules! numeric {
+    ($numeric:ty) => {
+        impl From<$numeric> for LogBias {
+            fn from(value: $numeric) -> Self {
+                match value {
+                    0 => Self::Latency,
+                    1 => Self::Throughput,
+                    _ => Self::Latency,
+                }
+            }
+        }
+
+        impl From<LogBias> for $numeric {
+            fn from(value: LogBias) -> Self {
+                match value {
+                    LogBias::Latency => 0,
+                    LogBias::Throughput => 1,
+                }
+            }
+        }
+    };
+}
+
+numeric!(i8);
+numeric!(i16);
+numeric!(i32);
+numeric!(i64);
+numeric!(u8);
+numeric!(u16);
+numeric!(u32);
+numeric!(u64);
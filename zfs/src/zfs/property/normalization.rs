@@ -0,0 +1,95 @@
+#![allow(clippy::use_self)]
+
+use std::fmt;
+use std::str;
+
+use serde::{Deserialize, Serialize};
+
+use super::*;
+
+// normalization=none|formC|formD|formKC|formKD
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Normalization {
+    #[default]
+    None,
+    FormC,
+    FormD,
+    FormKC,
+    FormKD,
+}
+
+impl Normalization {
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::None => "none",
+            Self::FormC => "formC",
+            Self::FormD => "formD",
+            Self::FormKC => "formKC",
+            Self::FormKD => "formKD",
+        }
+    }
+}
+
+impl AsRef<str> for Normalization {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for Normalization {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.as_str().fmt(f)
+    }
+}
+
+impl str::FromStr for Normalization {
+    type Err = error::InvalidProperty;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "none" => Ok(Self::None),
+            "formC" => Ok(Self::FormC),
+            "formD" => Ok(Self::FormD),
+            "formKC" => Ok(Self::FormKC),
+            "formKD" => Ok(Self::FormKD),
+            other => Err(error::InvalidProperty::invalid_value(other)),
+        }
+    }
+}
+
+// U8_TEXTPREP_NFC, NFD, NFKC and NFKD
+macro_rules! numeric {
+    ($numeric:ty) => {
+        impl From<$numeric> for Normalization {
+            fn from(value: $numeric) -> Self {
+                match value {
+                    0 => Self::None,
+                    0x50 => Self::FormC,
+                    0x10 => Self::FormD,
+                    0x60 => Self::FormKC,
+                    0x20 => Self::FormKD,
+                    _ => Self::None,
+                }
+            }
+        }
+
+        impl From<Normalization> for $numeric {
+            fn from(value: Normalization) -> Self {
+                match value {
+                    Normalization::None => 0,
+                    Normalization::FormC => 0x50,
+                    Normalization::FormD => 0x10,
+                    Normalization::FormKC => 0x60,
+                    Normalization::FormKD => 0x20,
+                }
+            }
+        }
+    };
+}
+
+numeric!(i16);
+numeric!(i32);
+numeric!(i64);
+numeric!(u16);
+numeric!(u32);
+numeric!(u64);
@@ -0,0 +1,91 @@
+#![allow(clippy::use_self)]
+
+use std::fmt;
+use std::str;
+
+use serde::{Deserialize, Serialize};
+
+use super::*;
+
+// redundant_metadata=all|most|some|none
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum RedundantMetadata {
+    #[default]
+    All,
+    Most,
+    Some,
+    None,
+}
+
+impl RedundantMetadata {
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::All => "all",
+            Self::Most => "most",
+            Self::Some => "some",
+            Self::None => "none",
+        }
+    }
+}
+
+impl AsRef<str> for RedundantMetadata {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for RedundantMetadata {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.as_str().fmt(f)
+    }
+}
+
+impl str::FromStr for RedundantMetadata {
+    type Err = error::InvalidProperty;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "all" => Ok(Self::All),
+            "most" => Ok(Self::Most),
+            "some" => Ok(Self::Some),
+            "none" => Ok(Self::None),
+            other => Err(error::InvalidProperty::invalid_value(other)),
+        }
+    }
+}
+
+macro_rules! numeric {
+    ($numeric:ty) => {
+        impl From<$numeric> for RedundantMetadata {
+            fn from(value: $numeric) -> Self {
+                match value {
+                    0 => Self::All,
+                    1 => Self::Most,
+                    2 => Self::Some,
+                    3 => Self::None,
+                    _ => Self::All,
+                }
+            }
+        }
+
+        impl From<RedundantMetadata> for $numeric {
+            fn from(value: RedundantMetadata) -> Self {
+                match value {
+                    RedundantMetadata::All => 0,
+                    RedundantMetadata::Most => 1,
+                    RedundantMetadata::Some => 2,
+                    RedundantMetadata::None => 3,
+                }
+            }
+        }
+    };
+}
+
+numeric!(i8);
+numeric!(i16);
+numeric!(i32);
+numeric!(i64);
+numeric!(u8);
+numeric!(u16);
+numeric!(u32);
+numeric!(u64);
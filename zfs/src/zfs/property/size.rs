@@ -0,0 +1,100 @@
+#![allow(clippy::use_self)]
+
+use std::fmt;
+use std::str;
+
+use serde::{Deserialize, Serialize};
+
+use super::*;
+
+// quota|refquota|reservation|refreservation=none|<size>
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Size {
+    #[default]
+    None,
+    Bytes(u64),
+}
+
+impl Size {
+    pub fn bytes(&self) -> Option<u64> {
+        match self {
+            Self::None => None,
+            Self::Bytes(bytes) => Some(*bytes),
+        }
+    }
+}
+
+impl fmt::Display for Size {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::None => "none".fmt(f),
+            Self::Bytes(bytes) => bytes.fmt(f),
+        }
+    }
+}
+
+impl str::FromStr for Size {
+    type Err = error::InvalidProperty;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "none" => Ok(Self::None),
+            other => parse_size(other).map(Self::from),
+        }
+    }
+}
+
+impl From<u64> for Size {
+    fn from(value: u64) -> Self {
+        match value {
+            0 => Self::None,
+            bytes => Self::Bytes(bytes),
+        }
+    }
+}
+
+impl From<Option<u64>> for Size {
+    fn from(value: Option<u64>) -> Self {
+        value.map_or(Self::None, Self::from)
+    }
+}
+
+impl From<Size> for u64 {
+    fn from(value: Size) -> Self {
+        value.bytes().unwrap_or(0)
+    }
+}
+
+/// Parse a size the way `zfs set` does ("512", "128K", "1.5G", "10TB")
+pub fn parse_size(s: &str) -> Result<u64, error::InvalidProperty> {
+    let invalid = || error::InvalidProperty::invalid_value(s);
+
+    let split = s
+        .find(|c: char| !c.is_ascii_digit() && c != '.')
+        .unwrap_or(s.len());
+    let (number, suffix) = s.split_at(split);
+    let suffix = suffix.to_ascii_uppercase();
+    let suffix = suffix.strip_suffix('B').unwrap_or(&suffix);
+    let shift = match suffix {
+        "" => 0,
+        "K" => 10,
+        "M" => 20,
+        "G" => 30,
+        "T" => 40,
+        "P" => 50,
+        "E" => 60,
+        _ => return Err(invalid()),
+    };
+
+    if let Ok(number) = number.parse::<u64>() {
+        return number.checked_mul(1 << shift).ok_or_else(invalid);
+    }
+
+    let number = number.parse::<f64>().map_err(|_| invalid())?;
+    let bytes = number * (1_u64 << shift) as f64;
+    if bytes.is_finite() && bytes >= 0.0 && bytes < u64::MAX as f64 {
+        Ok(bytes as u64)
+    } else {
+        Err(invalid())
+    }
+}
@@ -0,0 +1,81 @@
+#![allow(clippy::use_self)]
+
+use std::fmt;
+use std::str;
+
+use serde::{Deserialize, Serialize};
+
+use super::*;
+
+// snapdir=hidden|visible, also used by snapdev
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum SnapDir {
+    #[default]
+    Hidden,
+    Visible,
+}
+
+impl SnapDir {
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::Hidden => "hidden",
+            Self::Visible => "visible",
+        }
+    }
+}
+
+impl AsRef<str> for SnapDir {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for SnapDir {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.as_str().fmt(f)
+    }
+}
+
+impl str::FromStr for SnapDir {
+    type Err = error::InvalidProperty;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "hidden" => Ok(Self::Hidden),
+            "visible" => Ok(Self::Visible),
+            other => Err(error::InvalidProperty::invalid_value(other)),
+        }
+    }
+}
+
+macro_rules! numeric {
+    ($numeric:ty) => {
+        impl From<$numeric> for SnapDir {
+            fn from(value: $numeric) -> Self {
+                match value {
+                    0 => Self::Hidden,
+                    1 => Self::Visible,
+                    _ => Self::Hidden,
+                }
+            }
+        }
+
+        impl From<SnapDir> for $numeric {
+            fn from(value: SnapDir) -> Self {
+                match value {
+                    SnapDir::Hidden => 0,
+                    SnapDir::Visible => 1,
+                }
+            }
+        }
+    };
+}
+
+numeric!(i8);
+numeric!(i16);
+numeric!(i32);
+numeric!(i64);
+numeric!(u8);
+numeric!(u16);
+numeric!(u32);
+numeric!(u64);
@@ -0,0 +1,86 @@
+#![allow(clippy::use_self)]
+
+use std::fmt;
+use std::str;
+
+use serde::{Deserialize, Serialize};
+
+use super::*;
+
+// sync=standard|always|disabled
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum SyncMode {
+    #[default]
+    Standard,
+    Always,
+    Disabled,
+}
+
+impl SyncMode {
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::Standard => "standard",
+            Self::Always => "always",
+            Self::Disabled => "disabled",
+        }
+    }
+}
+
+impl AsRef<str> for SyncMode {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for SyncMode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.as_str().fmt(f)
+    }
+}
+
+impl str::FromStr for SyncMode {
+    type Err = error::InvalidProperty;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "standard" => Ok(Self::Standard),
+            "always" => Ok(Self::Always),
+            "disabled" => Ok(Self::Disabled),
+            other => Err(error::InvalidProperty::invalid_value(other)),
+        }
+    }
+}
+
+macro_rules! numeric {
+    ($numeric:ty) => {
+        impl From<$numeric> for SyncMode {
+            fn from(value: $numeric) -> Self {
+                match value {
+                    0 => Self::Standard,
+                    1 => Self::Always,
+                    2 => Self::Disabled,
+                    _ => Self::Standard,
+                }
+            }
+        }
+
+        impl From<SyncMode> for $numeric {
+            fn from(value: SyncMode) -> Self {
+                match value {
+                    SyncMode::Standard => 0,
+                    SyncMode::Always => 1,
+                    SyncMode::Disabled => 2,
+                }
+            }
+        }
+    };
+}
+
+numeric!(i8);
+numeric!(i16);
+numeric!(i32);
+numeric!(i64);
+numeric!(u8);
+numeric!(u16);
+numeric!(u32);
+numeric!(u64);
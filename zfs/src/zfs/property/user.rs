@@ -0,0 +1,82 @@
+use std::collections::BTreeMap;
+
+use super::*;
+
+// ZAP_MAXNAMELEN and ZFS_MAXPROPLEN, both including the terminating NUL
+const MAX_NAME_LEN: usize = 256;
+const MAX_VALUE_LEN: usize = 8192;
+
+// Source of a user property received with `zfs receive`
+const SOURCE_RECEIVED: &str = "$recvd";
+
+/// User property names are `module:property`, made of lowercase alphanumerics
+/// and `-`, `_`, `.` or `:`
+pub fn is_user_property(name: impl AsRef<str>) -> bool {
+    let name = name.as_ref();
+    name.contains(':')
+        && name.chars().all(|c| {
+            c.is_ascii_lowercase() || c.is_ascii_digit() || matches!(c, '-' | '_' | '.' | ':')
+        })
+}
+
+pub(crate) fn validate_user_property(name: &str, value: &str) -> Result<(), InvalidProperty> {
+    if !is_user_property(name) {
+        Err(InvalidProperty::not_user_property(name))
+    } else if name.len() >= MAX_NAME_LEN {
+        Err(InvalidProperty::too_long(name))
+    } else if value.len() >= MAX_VALUE_LEN {
+        Err(InvalidProperty::too_long(format!("value of {name}")))
+    } else {
+        Ok(())
+    }
+}
+
+pub(crate) fn user_properties(
+    dataset: &libzfs::ZfsHandle,
+) -> BTreeMap<String, PropertyValue<String>> {
+    let name = dataset.name();
+    let mut properties = BTreeMap::new();
+    for (property, value) in dataset.user_properties().items() {
+        let nvl = match value {
+            nvpair::Value::NvList(nvl) => nvl,
+            _ => continue,
+        };
+        let mut value = None;
+        let mut source = Source::Local;
+        for (key, item) in nvl.items() {
+            match (key.as_str(), item) {
+                ("value", nvpair::Value::String(text)) => value = Some(text),
+                ("source", nvpair::Value::String(from)) if from == SOURCE_RECEIVED => {
+                    source = Source::Received;
+                }
+                ("source", nvpair::Value::String(from)) if from != name => {
+                    source = Source::Inherited(from);
+                }
+                _ => {}
+            }
+        }
+        if let Some(value) = value {
+            properties.insert(property, PropertyValue::new(value, source));
+        }
+    }
+    properties
+}
+
+pub(crate) fn set_user_property(
+    dataset: &mut libzfs::ZfsHandle,
+    property: &str,
+    value: &str,
+) -> Result<()> {
+    let mut props = Properties::new();
+    props.user_property(property, value)?;
+    dataset.set_properties(props)?;
+    Ok(())
+}
+
+pub(crate) fn inherit_user_property(dataset: &mut libzfs::ZfsHandle, property: &str) -> Result<()> {
+    if !is_user_property(property) {
+        return Err(InvalidProperty::not_user_property(property).into());
+    }
+    dataset.inherit_property(property, false)?;
+    Ok(())
+}
@@ -0,0 +1,97 @@
+use std::fmt;
+use std::str;
+
+use serde::{Deserialize, Serialize};
+
+use libzfs::zprop_source_t;
+
+use super::*;
+
+/// Where the value of a property comes from
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Source {
+    /// Read-only statistics have no source
+    None,
+    Default,
+    Local,
+    /// Inherited from the named dataset
+    Inherited(String),
+    Received,
+    /// Set for the current mount only
+    Temporary,
+}
+
+impl Source {
+    fn new(source: zprop_source_t, from: String) -> Self {
+        match source {
+            zprop_source_t::ZPROP_SRC_DEFAULT => Self::Default,
+            zprop_source_t::ZPROP_SRC_LOCAL => Self::Local,
+            zprop_source_t::ZPROP_SRC_INHERITED => Self::Inherited(from),
+            zprop_source_t::ZPROP_SRC_RECEIVED => Self::Received,
+            zprop_source_t::ZPROP_SRC_TEMPORARY => Self::Temporary,
+            _ => Self::None,
+        }
+    }
+}
+
+impl fmt::Display for Source {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::None => "-".fmt(f),
+            Self::Default => "default".fmt(f),
+            Self::Local => "local".fmt(f),
+            Self::Inherited(from) => write!(f, "inherited from {from}"),
+            Self::Received => "received".fmt(f),
+            Self::Temporary => "temporary".fmt(f),
+        }
+    }
+}
+
+/// Value of a property along with its source, as shown by `zfs get`
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PropertyValue<T> {
+    value: T,
+    source: Source,
+}
+
+impl<T> PropertyValue<T> {
+    pub(crate) fn new(value: T, source: Source) -> Self {
+        Self { value, source }
+    }
+
+    pub fn value(&self) -> &T {
+        &self.value
+    }
+
+    pub fn source(&self) -> &Source {
+        &self.source
+    }
+
+    pub fn into_value(self) -> T {
+        self.value
+    }
+
+    /// The value is set on the dataset itself rather than inherited or defaulted
+    pub fn is_local(&self) -> bool {
+        self.source == Source::Local
+    }
+}
+
+impl<T: str::FromStr> PropertyValue<T> {
+    pub(crate) fn get<'a>(
+        dataset: &libzfs::ZfsHandle,
+        property: impl Property<'a>,
+    ) -> Result<Self, DatasetError> {
+        let name = property.name();
+        let property = libzfs::zfs_name_to_prop(&name)
+            .ok_or_else(|| InvalidProperty::no_such_property(&name))?;
+        let (value, source, from) = dataset
+            .property_with_source(property)
+            .ok_or_else(DatasetError::missing_value)?;
+        let value = value
+            .parse()
+            .map_err(|_| InvalidProperty::invalid_value(&value))?;
+        let source = Source::new(source, from);
+        Ok(Self { value, source })
+    }
+}
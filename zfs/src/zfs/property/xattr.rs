@@ -0,0 +1,97 @@
+#![allow(clippy::use_self)]
+
+use std::fmt;
+use std::str;
+
+use serde::{Deserialize, Serialize};
+
+use super::*;
+
+// xattr=on|off|dir|sa
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Xattr {
+    #[default]
+    On,
+    Off,
+    Sa,
+}
+
+impl Xattr {
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::On => "on",
+            Self::Off => "off",
+            Self::Sa => "sa",
+        }
+    }
+}
+
+impl AsRef<str> for Xattr {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for Xattr {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.as_str().fmt(f)
+    }
+}
+
+impl str::FromStr for Xattr {
+    type Err = error::InvalidProperty;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "on" => Ok(Self::On),
+            "off" => Ok(Self::Off),
+            "sa" => Ok(Self::Sa),
+            "dir" => Ok(Self::On),
+            other => Err(error::InvalidProperty::invalid_value(other)),
+        }
+    }
+}
+
+impl From<bool> for Xattr {
+    fn from(v: bool) -> Self {
+        if v {
+            Self::On
+        } else {
+            Self::Off
+        }
+    }
+}
+
+macro_rules! numeric {
+    ($numeric:ty) => {
+        impl From<$numeric> for Xattr {
+            fn from(value: $numeric) -> Self {
+                match value {
+                    1 => Self::On,
+                    0 => Self::Off,
+                    2 => Self::Sa,
+                    _ => Self::On,
+                }
+            }
+        }
+
+        impl From<Xattr> for $numeric {
+            fn from(value: Xattr) -> Self {
+                match value {
+                    Xattr::On => 1,
+                    Xattr::Off => 0,
+                    Xattr::Sa => 2,
+                }
+            }
+        }
+    };
+}
+
+numeric!(i8);
+numeric!(i16);
+numeric!(i32);
+numeric!(i64);
+numeric!(u8);
+numeric!(u16);
+numeric!(u32);
+numeric!(u64);
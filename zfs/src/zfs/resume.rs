@@ -0,0 +1,154 @@
+use std::fmt;
+use std::str;
+
+use super::*;
+
+use libzfs::zfs_prop_t::*;
+
+/// Decoded `receive_resume_token` of an interrupted resumable receive
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ResumeToken {
+    token: String,
+    toname: String,
+    toguid: u64,
+    fromguid: Option<u64>,
+    object: u64,
+    offset: u64,
+    bytes: u64,
+    options: SendOptions,
+}
+
+impl ResumeToken {
+    /// Decode a token as found in the `receive_resume_token` property
+    /// (`<version>-<checksum>-<length>-<hex encoded compressed nvlist>`)
+    pub fn decode(token: impl AsRef<str>) -> Result<Self> {
+        let token = token.as_ref();
+        let nvl = libzfs::zfs_send_resume_token_to_nvlist(token)
+            .map_err(|_| DatasetError::invalid_resume_token("failed to decode token"))?;
+
+        let mut resume = Self {
+            token: token.to_string(),
+            toname: String::new(),
+            toguid: 0,
+            fromguid: None,
+            object: 0,
+            offset: 0,
+            bytes: 0,
+            options: SendOptions::plain(),
+        };
+
+        for (name, value) in nvl.items() {
+            match (name.as_str(), value) {
+                ("toname", nvpair::Value::String(toname)) => resume.toname = toname,
+                ("toguid", nvpair::Value::U64(guid)) => resume.toguid = guid,
+                ("fromguid", nvpair::Value::U64(guid)) => resume.fromguid = Some(guid),
+                ("object", nvpair::Value::U64(object)) => resume.object = object,
+                ("offset", nvpair::Value::U64(offset)) => resume.offset = offset,
+                ("bytes", nvpair::Value::U64(bytes)) => resume.bytes = bytes,
+                ("embedok", _) => resume.options = resume.options.embed_data(true),
+                ("largeblockok", _) => resume.options = resume.options.large_block(true),
+                ("compressok", _) => resume.options = resume.options.compressed(true),
+                ("rawok", _) => resume.options = resume.options.raw(true),
+                ("savedok", _) => resume.options = resume.options.saved(true),
+                _ => {}
+            }
+        }
+
+        if resume.toname.is_empty() {
+            return Err(DatasetError::invalid_resume_token("token has no toname"));
+        }
+
+        Ok(resume)
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.token
+    }
+
+    /// Snapshot the interrupted stream was sent from
+    pub fn toname(&self) -> &str {
+        &self.toname
+    }
+
+    #[inline]
+    pub fn toguid(&self) -> u64 {
+        self.toguid
+    }
+
+    /// GUID of the incremental source, if the interrupted stream was incremental
+    #[inline]
+    pub fn fromguid(&self) -> Option<u64> {
+        self.fromguid
+    }
+
+    #[inline]
+    pub fn object(&self) -> u64 {
+        self.object
+    }
+
+    #[inline]
+    pub fn offset(&self) -> u64 {
+        self.offset
+    }
+
+    /// Bytes received before the interruption
+    #[inline]
+    pub fn bytes(&self) -> u64 {
+        self.bytes
+    }
+
+    /// Send options the interrupted stream was sent with
+    #[inline]
+    pub fn options(&self) -> SendOptions {
+        self.options
+    }
+
+    /// Snapshot or bookmark on the sending side matching `fromguid`
+    pub fn from(&self) -> Result<Option<String>> {
+        let fromguid = match self.fromguid {
+            Some(fromguid) => fromguid,
+            None => return Ok(None),
+        };
+
+        let filesystem = self
+            .toname
+            .split_once('@')
+            .map_or(self.toname.as_str(), |(filesystem, _)| filesystem);
+
+        let snapshot = libzfs::zfs_list_from(filesystem)
+            .snapshots()
+            .max_depth(1)
+            .iter()
+            .find(|snapshot| snapshot.numeric_property(ZFS_PROP_GUID) == fromguid)
+            .map(|snapshot| snapshot.name().to_string());
+        if snapshot.is_some() {
+            return Ok(snapshot);
+        }
+
+        Bookmark::list(filesystem)?
+            .into_iter()
+            .find(|bookmark| bookmark.guid() == fromguid)
+            .map(|bookmark| Some(bookmark.name()))
+            .ok_or_else(|| DatasetError::invalid_resume_token("incremental source not found"))
+    }
+}
+
+impl fmt::Display for ResumeToken {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.token.fmt(f)
+    }
+}
+
+impl AsRef<str> for ResumeToken {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl str::FromStr for ResumeToken {
+    type Err = DatasetError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::decode(s)
+    }
+}
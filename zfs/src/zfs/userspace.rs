@@ -0,0 +1,142 @@
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+
+use super::*;
+
+use libzfs::zfs_error;
+use libzfs::zfs_userquota_prop_t;
+use libzfs::zfs_userquota_prop_t::*;
+
+/// Whose space `Filesystem::userspace()` accounts for
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum UserquotaProp {
+    User,
+    Group,
+    Project,
+}
+
+impl UserquotaProp {
+    // used, quota, objused and objquota properties
+    fn properties(self) -> [zfs_userquota_prop_t; 4] {
+        match self {
+            Self::User => [
+                ZFS_PROP_USERUSED,
+                ZFS_PROP_USERQUOTA,
+                ZFS_PROP_USEROBJUSED,
+                ZFS_PROP_USEROBJQUOTA,
+            ],
+            Self::Group => [
+                ZFS_PROP_GROUPUSED,
+                ZFS_PROP_GROUPQUOTA,
+                ZFS_PROP_GROUPOBJUSED,
+                ZFS_PROP_GROUPOBJQUOTA,
+            ],
+            Self::Project => [
+                ZFS_PROP_PROJECTUSED,
+                ZFS_PROP_PROJECTQUOTA,
+                ZFS_PROP_PROJECTOBJUSED,
+                ZFS_PROP_PROJECTOBJQUOTA,
+            ],
+        }
+    }
+}
+
+/// Space and objects used by a user, group or project, along with its quotas
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SpaceEntry {
+    id: u32,
+    domain: Option<String>,
+    kind: UserquotaProp,
+    used: u64,
+    quota: property::Size,
+    objused: u64,
+    objquota: Option<u64>,
+}
+
+impl SpaceEntry {
+    fn new(kind: UserquotaProp, domain: &str, id: u32) -> Self {
+        Self {
+            id,
+            domain: Some(domain.to_string()).filter(|domain| !domain.is_empty()),
+            kind,
+            used: 0,
+            quota: property::Size::None,
+            objused: 0,
+            objquota: None,
+        }
+    }
+
+    /// UID, GID or project id
+    #[inline]
+    pub fn id(&self) -> u32 {
+        self.id
+    }
+
+    /// SID domain, for ids that are not POSIX ones
+    #[inline]
+    pub fn domain(&self) -> Option<&str> {
+        self.domain.as_deref()
+    }
+
+    #[inline]
+    pub fn kind(&self) -> UserquotaProp {
+        self.kind
+    }
+
+    #[inline]
+    pub fn used(&self) -> u64 {
+        self.used
+    }
+
+    #[inline]
+    pub fn quota(&self) -> property::Size {
+        self.quota
+    }
+
+    #[inline]
+    pub fn objused(&self) -> u64 {
+        self.objused
+    }
+
+    #[inline]
+    pub fn objquota(&self) -> Option<u64> {
+        self.objquota
+    }
+}
+
+type Setter = fn(&mut SpaceEntry, u64);
+
+pub(crate) fn userspace(
+    dataset: &libzfs::ZfsHandle,
+    kind: UserquotaProp,
+) -> Result<impl Iterator<Item = SpaceEntry>> {
+    let [used, quota, objused, objquota] = kind.properties();
+    let setters: [(zfs_userquota_prop_t, bool, Setter); 4] = [
+        (used, false, |entry, value| entry.used = value),
+        (quota, false, |entry, value| entry.quota = value.into()),
+        (objused, true, |entry, value| entry.objused = value),
+        (objquota, true, |entry, value| {
+            entry.objquota = Some(value).filter(|quota| *quota != 0);
+        }),
+    ];
+
+    let mut entries = BTreeMap::new();
+    for (property, optional, set) in setters {
+        let values = match dataset.userspace(property) {
+            Ok(values) => values,
+            // Object accounting requires the userobj_accounting feature,
+            // libzfs reports it missing as EZFS_BADVERSION
+            Err(err) if optional && err.error() == zfs_error::EZFS_BADVERSION => continue,
+            Err(err) => return Err(err.into()),
+        };
+        for (domain, id, value) in values {
+            let entry = entries
+                .entry((domain, id))
+                .or_insert_with_key(|(domain, id)| SpaceEntry::new(kind, domain, *id));
+            set(entry, value);
+        }
+    }
+
+    Ok(entries.into_values())
+}
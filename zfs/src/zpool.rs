@@ -0,0 +1,844 @@
+use std::collections::BTreeMap;
+use std::ffi;
+use std::str::FromStr;
+use std::time::{Duration, SystemTime};
+
+use serde::Serialize;
+
+use super::*;
+
+use libzfs::zfs_error;
+use libzfs::zpool_prop_t::*;
+use property::FeatureState;
+use zfs::property::InvalidProperty;
+
+pub use builder::Vdev;
+pub use builder::VdevSpec;
+pub use builder::ZpoolBuilder;
+pub use lzc::VdevAction;
+pub use status::PoolStatus;
+pub use status::ScanFunction;
+pub use status::ScanState;
+pub use status::ScanStatus;
+pub use status::ScrubAction;
+pub use status::VdevStatus;
+
+const ZPOOL_CONFIG_VDEV_TREE: &str = "vdev_tree";
+const ZPOOL_CONFIG_CHECKPOINT_STATS: &str = "checkpoint_stats";
+const ZPOOL_CONFIG_SCAN_STATS: &str = "scan_stats";
+const ZPOOL_CONFIG_CHILDREN: &str = "children";
+const ZPOOL_CONFIG_PATH: &str = "path";
+const ZPOOL_CONFIG_GUID: &str = "guid";
+const ZPOOL_CONFIG_VDEV_STATS: &str = "vdev_stats";
+const ZPOOL_CONFIG_TYPE: &str = "type";
+const ZPOOL_CONFIG_IS_LOG: &str = "is_log";
+const ZPOOL_CONFIG_NPARITY: &str = "nparity";
+const ZPOOL_CONFIG_ALLOCATION_BIAS: &str = "alloc_bias";
+const ZPOOL_CONFIG_L2CACHE: &str = "l2cache";
+const ZPOOL_CONFIG_SPARES: &str = "spares";
+const ZPOOL_CONFIG_DRAID_NDATA: &str = "draid_ndata";
+const ZPOOL_CONFIG_DRAID_NSPARES: &str = "draid_nspares";
+
+const VDEV_TYPE_RAIDZ: &str = "raidz";
+const VDEV_TYPE_DRAID: &str = "draid";
+
+const VDEV_ALLOC_BIAS_LOG: &str = "log";
+const VDEV_ALLOC_BIAS_SPECIAL: &str = "special";
+
+// vdev_stat_t
+const VS_INITIALIZE_ERRORS: usize = 23;
+const VS_INITIALIZE_BYTES_DONE: usize = 28;
+const VS_INITIALIZE_BYTES_EST: usize = 29;
+const VS_INITIALIZE_STATE: usize = 30;
+const VS_INITIALIZE_ACTION_TIME: usize = 31;
+const VS_TRIM_ERRORS: usize = 35;
+const VS_TRIM_BYTES_DONE: usize = 37;
+const VS_TRIM_BYTES_EST: usize = 38;
+const VS_TRIM_STATE: usize = 39;
+const VS_TRIM_ACTION_TIME: usize = 40;
+
+// vdev_initializing_state_t and vdev_trim_state_t
+const VDEV_STATE_ACTIVE: u64 = 1;
+const VDEV_STATE_CANCELED: u64 = 2;
+const VDEV_STATE_SUSPENDED: u64 = 3;
+const VDEV_STATE_COMPLETE: u64 = 4;
+
+const FEATURE_PREFIX: &str = "feature@";
+
+// checkpoint_state_t
+const CS_CHECKPOINT_EXISTS: u64 = 1;
+const CS_CHECKPOINT_DISCARDING: u64 = 2;
+
+pub mod property;
+
+mod builder;
+mod status;
+
+#[derive(Debug)]
+pub struct Zpool {
+    pool: libzfs::ZpoolHandle,
+}
+
+impl Zpool {
+    pub fn builder() -> ZpoolBuilder {
+        ZpoolBuilder::new()
+    }
+
+    pub fn get(name: impl AsRef<str>) -> Result<Self> {
+        let name = name.as_ref();
+        let cname = ffi::CString::new(name)?;
+        let pool = libzfs::ZpoolHandle::new(cname).map_err(|error| pool_error(name, error))?;
+
+        Ok(Self { pool })
+    }
+
+    /// Import the exported pool `name`, searching `dirs` for its devices
+    /// (the default device directories if empty), and mount its filesystems
+    pub fn import(name: impl AsRef<str>, dirs: &[impl AsRef<str>]) -> Result<Self> {
+        Self::import_impl(name.as_ref(), None, dirs)
+    }
+
+    /// Same as `import()`, renaming the pool to `newname`
+    pub fn import_as(
+        name: impl AsRef<str>,
+        newname: impl AsRef<str>,
+        dirs: &[impl AsRef<str>],
+    ) -> Result<Self> {
+        Self::import_impl(name.as_ref(), Some(newname.as_ref()), dirs)
+    }
+
+    /// Unmount the filesystems of the pool and destroy it
+    pub fn destroy(self, force: bool) -> Result<()> {
+        let name = self.name();
+        self.pool
+            .destroy(force)
+            .map_err(|error| pool_error(&name, error))
+    }
+
+    /// Unmount the filesystems of the pool and export it
+    pub fn export(self, force: bool) -> Result<()> {
+        let name = self.name();
+        self.pool
+            .export(force)
+            .map_err(|error| pool_error(&name, error))
+    }
+
+    /// All the imported pools
+    pub fn list() -> Vec<Self> {
+        libzfs::zpool_list()
+            .into_iter()
+            .map(|pool| Self { pool })
+            .collect()
+    }
+
+    pub fn name(&self) -> String {
+        self.pool.name().to_string()
+    }
+
+    pub fn guid(&self) -> u64 {
+        self.pool.numeric_property(ZPOOL_PROP_GUID)
+    }
+
+    pub fn health(&self) -> Result<property::Health> {
+        self.property(ZPOOL_PROP_HEALTH)
+    }
+
+    pub fn size(&self) -> u64 {
+        self.pool.numeric_property(ZPOOL_PROP_SIZE)
+    }
+
+    pub fn allocated(&self) -> Result<property::Allocated> {
+        self.property(ZPOOL_PROP_ALLOCATED)
+    }
+
+    pub fn free(&self) -> u64 {
+        self.pool.numeric_property(ZPOOL_PROP_FREE)
+    }
+
+    /// Percentage of the pool space in use
+    pub fn capacity(&self) -> u64 {
+        self.pool.numeric_property(ZPOOL_PROP_CAPACITY)
+    }
+
+    pub fn fragmentation(&self) -> u64 {
+        self.pool.numeric_property(ZPOOL_PROP_FRAGMENTATION)
+    }
+
+    pub fn expandsize(&self) -> Result<property::Expandsize> {
+        self.property(ZPOOL_PROP_EXPANDSZ)
+    }
+
+    pub fn altroot(&self) -> Result<property::Altroot> {
+        self.property(ZPOOL_PROP_ALTROOT)
+    }
+
+    pub fn ashift(&self) -> Result<property::Ashift> {
+        self.property(ZPOOL_PROP_ASHIFT)
+    }
+
+    pub fn bootfs(&self) -> Result<property::Bootfs> {
+        self.property(ZPOOL_PROP_BOOTFS)
+    }
+
+    pub fn cachefile(&self) -> Result<property::Cachefile> {
+        self.property(ZPOOL_PROP_CACHEFILE)
+    }
+
+    pub fn failmode(&self) -> Result<property::Failmode> {
+        self.property(ZPOOL_PROP_FAILUREMODE)
+    }
+
+    /// Legacy on-disk version, `Version::Empty` for pools using feature flags
+    pub fn version(&self) -> Result<property::Version> {
+        self.property(ZPOOL_PROP_VERSION)
+    }
+
+    pub fn readonly(&self) -> Result<property::OnOff> {
+        self.property(ZPOOL_PROP_READONLY)
+    }
+
+    pub fn autoexpand(&self) -> Result<property::OnOff> {
+        self.property(ZPOOL_PROP_AUTOEXPAND)
+    }
+
+    pub fn autoreplace(&self) -> Result<property::OnOff> {
+        self.property(ZPOOL_PROP_AUTOREPLACE)
+    }
+
+    pub fn autotrim(&self) -> Result<property::OnOff> {
+        self.property(ZPOOL_PROP_AUTOTRIM)
+    }
+
+    pub fn delegation(&self) -> Result<property::OnOff> {
+        self.property(ZPOOL_PROP_DELEGATION)
+    }
+
+    pub fn listsnapshots(&self) -> Result<property::OnOff> {
+        self.property(ZPOOL_PROP_LISTSNAPS)
+    }
+
+    pub fn multihost(&self) -> Result<property::OnOff> {
+        self.property(ZPOOL_PROP_MULTIHOST)
+    }
+
+    /// Vdev tree, errors, scrub or resilver progress and health status as of the last refresh,
+    /// as `zpool status` reports them
+    pub fn status(&self) -> Result<PoolStatus> {
+        PoolStatus::from_pool(self)
+    }
+
+    pub fn set(&mut self) -> ZpoolPropSetter<'_> {
+        ZpoolPropSetter::new(self)
+    }
+
+    /// Value of any native, `feature@<name>` or user (`module:property`) property,
+    /// as `zpool get -p` shows it. Pools have user properties since OpenZFS 2.2.
+    pub fn get_property(&self, name: impl AsRef<str>) -> Result<String> {
+        let name = name.as_ref();
+        if let Some(feature) = name.strip_prefix(FEATURE_PREFIX) {
+            return Ok(self.feature(feature)?.to_string());
+        }
+        if name.contains(':') {
+            return self
+                .pool
+                .user_property(name)
+                .map_err(|error| pool_error(&self.name(), error));
+        }
+        let property = libzfs::zpool_name_to_prop(name)
+            .ok_or_else(|| InvalidProperty::no_such_property(name))?;
+        let value = self
+            .pool
+            .literal_property(property)
+            .ok_or_else(DatasetError::missing_value)?;
+        Ok(value)
+    }
+
+    pub fn feature(&self, name: impl AsRef<str>) -> Result<FeatureState> {
+        let name = name.as_ref();
+        let state = self
+            .pool
+            .feature(format!("{FEATURE_PREFIX}{name}"))
+            .map_err(|_| InvalidProperty::no_such_property(name))?;
+        Ok(state.parse()?)
+    }
+
+    /// States of all the features known to libzfs, by name
+    pub fn features(&self) -> BTreeMap<String, FeatureState> {
+        libzfs::zpool_feature_names()
+            .into_iter()
+            .filter_map(|name| {
+                let state = self.feature(&name).ok()?;
+                Some((name, state))
+            })
+            .collect()
+    }
+
+    /// Enable the feature `name`, it becomes active once used
+    pub fn enable_feature(&self, name: impl AsRef<str>) -> Result<()> {
+        let name = name.as_ref();
+        let name = name.strip_prefix(FEATURE_PREFIX).unwrap_or(name);
+        self.pool
+            .set_property(
+                format!("{FEATURE_PREFIX}{name}"),
+                FeatureState::Enabled.as_str(),
+            )
+            .map_err(|error| pool_error(&self.name(), error))
+    }
+
+    /// Reload the pool configuration and statistics
+    pub fn refresh(&mut self) -> Result<()> {
+        self.pool.refresh_stats()?;
+        Ok(())
+    }
+
+    /// Checkpoint the pool, e.g. before enabling features or changing the vdev layout.
+    /// The checkpoint is kept, so that the pool can be rewound to it, until it is committed.
+    pub fn checkpoint(&self) -> Result<Checkpoint> {
+        let pool = self.name();
+        lzc::checkpoint(&pool)?;
+        Ok(Checkpoint { pool })
+    }
+
+    /// Discard the checkpoint of this pool
+    pub fn discard_checkpoint(&self) -> Result<()> {
+        lzc::discard_checkpoint(self.name())?;
+        Ok(())
+    }
+
+    /// Checkpoint of this pool as of the last refresh, `None` if there is none
+    pub fn checkpoint_status(&self) -> Option<CheckpointStatus> {
+        let config = self.pool.config()?;
+        let vdev_tree = config.lookup_nvpair(ZPOOL_CONFIG_VDEV_TREE).ok()??;
+        let stats = vdev_tree
+            .nvlist()
+            .lookup_nvpair(ZPOOL_CONFIG_CHECKPOINT_STATS)
+            .ok()??;
+        CheckpointStatus::from_stats(stats.uint64_array())
+    }
+
+    /// Start, pause or stop a scrub
+    pub fn scrub(&self, action: ScrubAction) -> Result<()> {
+        let (func, cmd) = action.scan_args();
+        self.pool
+            .scan(func, cmd)
+            .map_err(|error| pool_error(&self.name(), error))
+    }
+
+    /// Last or current scrub or resilver as of the last refresh, `None` if there has been none
+    pub fn scan_stats(&self) -> Option<ScanStatus> {
+        let config = self.pool.config()?;
+        let vdev_tree = config.lookup_nvpair(ZPOOL_CONFIG_VDEV_TREE).ok()??;
+        let stats = vdev_tree
+            .nvlist()
+            .lookup_nvpair(ZPOOL_CONFIG_SCAN_STATS)
+            .ok()??;
+        ScanStatus::from_stats(stats.uint64_array())
+    }
+
+    /// Block until no `activity` is in progress, returning whether it had to wait
+    #[cfg(feature = "wait")]
+    pub fn wait(&self, activity: zpool_wait_activity_t) -> Result<bool> {
+        let waited = lzc::wait(self.name(), activity)?;
+        Ok(waited)
+    }
+
+    /// Block until `activity` identified by `tag` (the guid of a vdev being initialized,
+    /// trimmed or replaced) is no longer in progress, returning whether it had to wait
+    #[cfg(feature = "wait")]
+    pub fn wait_tag(&self, activity: zpool_wait_activity_t, tag: u64) -> Result<bool> {
+        let waited = lzc::wait_tag(self.name(), activity, tag)?;
+        Ok(waited)
+    }
+
+    /// Same as `wait()`, blocking a thread of the tokio blocking pool instead of the caller
+    #[cfg(feature = "wait")]
+    pub fn wait_async(
+        &self,
+        activity: zpool_wait_activity_t,
+    ) -> impl std::future::Future<Output = Result<bool>> {
+        let pool = self.name();
+        wait::spawn_blocking(move || lzc::wait(pool, activity))
+    }
+
+    /// Start, cancel or suspend initializing `vdevs` (leaf vdev paths), all of them if empty
+    pub fn initialize(
+        &self,
+        vdevs: impl IntoIterator<Item = impl AsRef<str>>,
+        action: VdevAction,
+    ) -> Result<()> {
+        let vdevs = self.select_vdevs(vdevs)?;
+        lzc::initialize(self.name(), vdevs, action)?;
+        Ok(())
+    }
+
+    /// Start, cancel or suspend TRIM of `vdevs` (leaf vdev paths), all of them if empty.
+    /// `rate` is in bytes per second per vdev, 0 for no limit.
+    pub fn trim(
+        &self,
+        vdevs: impl IntoIterator<Item = impl AsRef<str>>,
+        rate: u64,
+        secure: bool,
+        action: VdevAction,
+    ) -> Result<()> {
+        let vdevs = self.select_vdevs(vdevs)?;
+        lzc::trim(self.name(), vdevs, rate, secure, action)?;
+        Ok(())
+    }
+
+    /// Add `vdevs` to the pool
+    pub fn add(&self, vdevs: VdevSpec) -> Result<()> {
+        let nvroot = vdevs.nvroot()?;
+        self.pool
+            .add(&nvroot)
+            .map_err(|error| pool_error(&self.name(), error))
+    }
+
+    /// Attach `new` to the vdev `existing`, turning it into a mirror
+    /// (or adding a side to its mirror) and resilvering `new`
+    pub fn attach(&self, existing: impl AsRef<str>, new: impl AsRef<str>) -> Result<()> {
+        self.attach_impl(existing.as_ref(), new.as_ref(), false)
+    }
+
+    /// Detach `vdev` from its mirror, or a replaced or spare device
+    pub fn detach(&self, vdev: impl AsRef<str>) -> Result<()> {
+        let vdev = vdev.as_ref();
+        self.pool
+            .detach(vdev)
+            .map_err(|error| self.vdev_error(vdev, error))
+    }
+
+    /// Replace `old` with `new`, `old` is detached once `new` is resilvered
+    pub fn replace(&self, old: impl AsRef<str>, new: impl AsRef<str>) -> Result<()> {
+        self.attach_impl(old.as_ref(), new.as_ref(), true)
+    }
+
+    /// Bring `vdev` back online, using all of its space if `expand`, returning its new health
+    pub fn online(&self, vdev: impl AsRef<str>, expand: bool) -> Result<property::Health> {
+        let vdev = vdev.as_ref();
+        let state = self
+            .pool
+            .online(vdev, expand)
+            .map_err(|error| self.vdev_error(vdev, error))?;
+        Ok(status::vdev_health(state as u64, 0))
+    }
+
+    /// Take `vdev` offline, only until the next import if `temporary`
+    pub fn offline(&self, vdev: impl AsRef<str>, temporary: bool) -> Result<()> {
+        let vdev = vdev.as_ref();
+        self.pool
+            .offline(vdev, temporary)
+            .map_err(|error| self.vdev_error(vdev, error))
+    }
+
+    /// Remove a top-level, log, cache or spare `vdev`, evacuating its data first
+    pub fn remove(&self, vdev: impl AsRef<str>) -> Result<()> {
+        let vdev = vdev.as_ref();
+        self.pool
+            .remove(vdev)
+            .map_err(|error| self.vdev_error(vdev, error))
+    }
+
+    /// Clear the error counters of `vdev`, of all the vdevs if `None`
+    pub fn clear(&self, vdev: Option<&str>) -> Result<()> {
+        self.pool.clear(vdev).map_err(|error| match vdev {
+            Some(vdev) => self.vdev_error(vdev, error),
+            None => pool_error(&self.name(), error),
+        })
+    }
+
+    /// Initialize and TRIM progress of the leaf vdevs as of the last refresh
+    pub fn vdev_progress(&self) -> Vec<VdevProgress> {
+        let mut progress = Vec::new();
+        if let Some(config) = self.pool.config() {
+            if let Ok(Some(vdev_tree)) = config.lookup_nvpair(ZPOOL_CONFIG_VDEV_TREE) {
+                collect_leaves(&vdev_tree.nvlist(), &mut progress);
+            }
+        }
+        progress
+    }
+
+    fn import_impl(name: &str, newname: Option<&str>, dirs: &[impl AsRef<str>]) -> Result<Self> {
+        let config = libzfs::zpool_find_import(name, dirs)?
+            .ok_or_else(|| DatasetError::PoolNotFound(name.to_string()))?;
+        let imported = newname.unwrap_or(name);
+        libzfs::zpool_import(&config, newname).map_err(|error| pool_error(imported, error))?;
+
+        let pool = Self::get(imported)?;
+        pool.pool.mount_datasets()?;
+        Ok(pool)
+    }
+
+    fn attach_impl(&self, old: &str, new: &str, replacing: bool) -> Result<()> {
+        let nvroot = VdevSpec::device_nvroot(new)?;
+        self.pool
+            .attach(old, new, &nvroot, replacing)
+            .map_err(|error| self.vdev_error(old, error))
+    }
+
+    fn vdev_error(&self, vdev: &str, error: libzfs::ZfsError) -> DatasetError {
+        match error.error() {
+            zfs_error::EZFS_NODEVICE => DatasetError::UnknownVdev(vdev.to_string()),
+            _ => pool_error(&self.name(), error),
+        }
+    }
+
+    fn property<T>(&self, property: libzfs::zpool_prop_t) -> Result<T>
+    where
+        T: FromStr<Err = InvalidProperty>,
+    {
+        let value = self
+            .pool
+            .literal_property(property)
+            .ok_or_else(DatasetError::missing_value)?;
+        Ok(value.parse()?)
+    }
+
+    fn select_vdevs(
+        &self,
+        vdevs: impl IntoIterator<Item = impl AsRef<str>>,
+    ) -> Result<Vec<(String, u64)>> {
+        let leaves = self.vdev_progress();
+        let mut selected = Vec::new();
+        for vdev in vdevs {
+            let vdev = vdev.as_ref();
+            let leaf = leaves
+                .iter()
+                .find(|leaf| leaf.name == vdev)
+                .ok_or_else(|| DatasetError::UnknownVdev(vdev.to_string()))?;
+            selected.push((leaf.name.clone(), leaf.guid));
+        }
+        if selected.is_empty() {
+            selected = leaves
+                .into_iter()
+                .map(|leaf| (leaf.name, leaf.guid))
+                .collect();
+        }
+        Ok(selected)
+    }
+}
+
+fn pool_error(name: &str, error: libzfs::ZfsError) -> DatasetError {
+    let name = name.to_string();
+    match error.error() {
+        zfs_error::EZFS_EXISTS => DatasetError::PoolExists(name),
+        zfs_error::EZFS_NOENT => DatasetError::PoolNotFound(name),
+        zfs_error::EZFS_BUSY => DatasetError::PoolBusy(name),
+        zfs_error::EZFS_BADDEV | zfs_error::EZFS_INVALCONFIG | zfs_error::EZFS_DEVOVERFLOW => {
+            DatasetError::InvalidVdev(error.to_string())
+        }
+        _ => error.into(),
+    }
+}
+
+fn collect_leaves<T>(vdev: &nvpair::NvListRef<'_, T>, leaves: &mut Vec<VdevProgress>) {
+    match vdev.lookup_nvpair(ZPOOL_CONFIG_CHILDREN) {
+        Ok(Some(children)) => {
+            for child in children.nvlist_array() {
+                collect_leaves(&child, leaves);
+            }
+        }
+        _ => leaves.extend(VdevProgress::from_config(vdev)),
+    }
+}
+
+#[derive(Debug)]
+pub struct ZpoolPropSetter<'a> {
+    pool: &'a mut Zpool,
+    props: Vec<(String, String)>,
+}
+
+impl<'a> ZpoolPropSetter<'a> {
+    pub fn new(pool: &'a mut Zpool) -> Self {
+        Self {
+            pool,
+            props: Vec::new(),
+        }
+    }
+
+    pub fn autoexpand(self, value: impl Into<property::OnOff>) -> Self {
+        self.property("autoexpand", value.into().as_str())
+    }
+
+    pub fn autoreplace(self, value: impl Into<property::OnOff>) -> Self {
+        self.property("autoreplace", value.into().as_str())
+    }
+
+    pub fn autotrim(self, value: impl Into<property::OnOff>) -> Self {
+        self.property("autotrim", value.into().as_str())
+    }
+
+    pub fn bootfs(self, value: impl Into<property::Bootfs>) -> Self {
+        let value = match value.into() {
+            property::Bootfs::BootableDataset(dataset) => dataset,
+            property::Bootfs::Empty => String::new(),
+        };
+        self.property("bootfs", value)
+    }
+
+    pub fn cachefile(self, value: impl Into<property::Cachefile>) -> Self {
+        let value = match value.into() {
+            property::Cachefile::File(file) => file,
+            property::Cachefile::Empty => String::new(),
+        };
+        self.property("cachefile", value)
+    }
+
+    pub fn comment(self, value: impl AsRef<str>) -> Self {
+        self.property("comment", value)
+    }
+
+    pub fn delegation(self, value: impl Into<property::OnOff>) -> Self {
+        self.property("delegation", value.into().as_str())
+    }
+
+    pub fn failmode(self, value: impl Into<property::Failmode>) -> Self {
+        self.property("failmode", value.into().as_str())
+    }
+
+    pub fn listsnapshots(self, value: impl Into<property::OnOff>) -> Self {
+        self.property("listsnapshots", value.into().as_str())
+    }
+
+    pub fn multihost(self, value: impl Into<property::OnOff>) -> Self {
+        self.property("multihost", value.into().as_str())
+    }
+
+    /// Enable the feature `name`
+    pub fn feature(self, name: impl AsRef<str>) -> Self {
+        let name = format!("{FEATURE_PREFIX}{}", name.as_ref());
+        self.property(name, FeatureState::Enabled.as_str())
+    }
+
+    /// Any pool property, including `feature@<name>` and user properties
+    pub fn property(mut self, name: impl AsRef<str>, value: impl AsRef<str>) -> Self {
+        let name = name.as_ref().to_string();
+        self.props.push((name, value.as_ref().to_string()));
+        self
+    }
+
+    /// Set the properties one at a time, stopping at the first failure
+    pub fn commit(self) -> Result<()> {
+        for (name, value) in &self.props {
+            self.pool
+                .pool
+                .set_property(name, value)
+                .map_err(|error| pool_error(&self.pool.name(), error))?;
+        }
+        Ok(())
+    }
+}
+
+/// Checkpoint taken by `Zpool::checkpoint()`.
+/// Dropping it keeps the checkpoint in place, `commit()` discards it.
+#[derive(Debug)]
+#[must_use = "the checkpoint is kept until committed"]
+pub struct Checkpoint {
+    pool: String,
+}
+
+impl Checkpoint {
+    pub fn pool(&self) -> &str {
+        &self.pool
+    }
+
+    pub fn status(&self) -> Result<Option<CheckpointStatus>> {
+        let status = Zpool::get(&self.pool)?.checkpoint_status();
+        Ok(status)
+    }
+
+    /// The change went well, discard the checkpoint
+    pub fn commit(self) -> Result<()> {
+        lzc::discard_checkpoint(&self.pool)?;
+        Ok(())
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize)]
+pub enum CheckpointState {
+    Exists,
+    Discarding,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize)]
+pub struct CheckpointStatus {
+    state: CheckpointState,
+    start_time: SystemTime,
+    space: u64,
+}
+
+impl CheckpointStatus {
+    pub fn state(&self) -> CheckpointState {
+        self.state
+    }
+
+    pub fn start_time(&self) -> SystemTime {
+        self.start_time
+    }
+
+    /// Space held by the checkpoint
+    pub fn space(&self) -> u64 {
+        self.space
+    }
+
+    // pool_checkpoint_stat_t
+    fn from_stats(stats: &[u64]) -> Option<Self> {
+        let state = match *stats.first()? {
+            CS_CHECKPOINT_EXISTS => CheckpointState::Exists,
+            CS_CHECKPOINT_DISCARDING => CheckpointState::Discarding,
+            _ => return None,
+        };
+        let start_time = SystemTime::UNIX_EPOCH + Duration::from_secs(*stats.get(1)?);
+        let space = *stats.get(2)?;
+
+        Some(Self {
+            state,
+            start_time,
+            space,
+        })
+    }
+}
+
+/// Initialize and TRIM progress of a leaf vdev
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct VdevProgress {
+    name: String,
+    guid: u64,
+    initialize: Progress,
+    trim: Progress,
+}
+
+impl VdevProgress {
+    /// Path of the vdev
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn guid(&self) -> u64 {
+        self.guid
+    }
+
+    pub fn initialize(&self) -> Progress {
+        self.initialize
+    }
+
+    pub fn trim(&self) -> Progress {
+        self.trim
+    }
+
+    fn from_config<T>(vdev: &nvpair::NvListRef<'_, T>) -> Option<Self> {
+        let name = vdev
+            .lookup_nvpair(ZPOOL_CONFIG_PATH)
+            .ok()??
+            .string()
+            .into_owned();
+        let guid = vdev.lookup_nvpair(ZPOOL_CONFIG_GUID).ok()??.uint64();
+        let stats = vdev.lookup_nvpair(ZPOOL_CONFIG_VDEV_STATS).ok()??;
+        let stats = stats.uint64_array();
+        let initialize = Progress::from_stats(
+            stats,
+            VS_INITIALIZE_STATE,
+            VS_INITIALIZE_BYTES_DONE,
+            VS_INITIALIZE_BYTES_EST,
+            VS_INITIALIZE_ACTION_TIME,
+            VS_INITIALIZE_ERRORS,
+        );
+        let trim = Progress::from_stats(
+            stats,
+            VS_TRIM_STATE,
+            VS_TRIM_BYTES_DONE,
+            VS_TRIM_BYTES_EST,
+            VS_TRIM_ACTION_TIME,
+            VS_TRIM_ERRORS,
+        );
+
+        Some(Self {
+            name,
+            guid,
+            initialize,
+            trim,
+        })
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize)]
+pub enum ProgressState {
+    None,
+    Active,
+    Canceled,
+    Suspended,
+    Complete,
+}
+
+/// Progress of initializing or TRIM of a vdev
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize)]
+pub struct Progress {
+    state: ProgressState,
+    bytes_done: u64,
+    bytes_est: u64,
+    action_time: Option<SystemTime>,
+    errors: u64,
+}
+
+impl Progress {
+    pub fn state(&self) -> ProgressState {
+        self.state
+    }
+
+    pub fn bytes_done(&self) -> u64 {
+        self.bytes_done
+    }
+
+    /// Estimated total bytes to process
+    pub fn bytes_est(&self) -> u64 {
+        self.bytes_est
+    }
+
+    /// When the operation was last started, suspended, canceled or completed
+    pub fn action_time(&self) -> Option<SystemTime> {
+        self.action_time
+    }
+
+    pub fn errors(&self) -> u64 {
+        self.errors
+    }
+
+    /// Completion in percents
+    pub fn percent(&self) -> f64 {
+        if self.bytes_est == 0 {
+            0.0
+        } else {
+            self.bytes_done as f64 * 100.0 / self.bytes_est as f64
+        }
+    }
+
+    // Older kernels report shorter vdev_stat_t, missing fields read as zero
+    fn from_stats(
+        stats: &[u64],
+        state: usize,
+        bytes_done: usize,
+        bytes_est: usize,
+        action_time: usize,
+        errors: usize,
+    ) -> Self {
+        let stat = |index: usize| stats.get(index).copied().unwrap_or_default();
+        let state = match stat(state) {
+            VDEV_STATE_ACTIVE => ProgressState::Active,
+            VDEV_STATE_CANCELED => ProgressState::Canceled,
+            VDEV_STATE_SUSPENDED => ProgressState::Suspended,
+            VDEV_STATE_COMPLETE => ProgressState::Complete,
+            _ => ProgressState::None,
+        };
+        let action_time = match stat(action_time) {
+            0 => None,
+            secs => Some(SystemTime::UNIX_EPOCH + Duration::from_secs(secs)),
+        };
+
+        Self {
+            state,
+            bytes_done: stat(bytes_done),
+            bytes_est: stat(bytes_est),
+            action_time,
+            errors: stat(errors),
+        }
+    }
+}
@@ -0,0 +1,393 @@
+use std::fs;
+use std::os::unix::fs::FileTypeExt;
+
+use super::*;
+
+const ZPOOL_CONFIG_WHOLE_DISK: &str = "whole_disk";
+const ZPOOL_CONFIG_ASHIFT: &str = "ashift";
+const ZPOOL_CONFIG_DRAID_NGROUPS: &str = "draid_ngroups";
+
+const VDEV_TYPE_ROOT: &str = "root";
+const VDEV_TYPE_MIRROR: &str = "mirror";
+const VDEV_TYPE_DISK: &str = "disk";
+const VDEV_TYPE_FILE: &str = "file";
+
+const DEV_DIR: &str = "/dev/";
+
+/// Top-level vdev of a new pool. Devices are paths of disks or files,
+/// names without a leading `/` are looked up in `/dev`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Vdev {
+    Device(String),
+    Mirror(Vec<String>),
+    Raidz {
+        parity: u64,
+        devices: Vec<String>,
+    },
+    Draid {
+        parity: u64,
+        data: u64,
+        spares: u64,
+        devices: Vec<String>,
+    },
+}
+
+impl Vdev {
+    pub fn device(device: impl AsRef<str>) -> Self {
+        Self::Device(device.as_ref().to_string())
+    }
+
+    pub fn mirror(devices: impl IntoIterator<Item = impl AsRef<str>>) -> Self {
+        Self::Mirror(devices_from(devices))
+    }
+
+    pub fn raidz1(devices: impl IntoIterator<Item = impl AsRef<str>>) -> Self {
+        Self::raidz(1, devices)
+    }
+
+    pub fn raidz2(devices: impl IntoIterator<Item = impl AsRef<str>>) -> Self {
+        Self::raidz(2, devices)
+    }
+
+    pub fn raidz3(devices: impl IntoIterator<Item = impl AsRef<str>>) -> Self {
+        Self::raidz(3, devices)
+    }
+
+    /// dRAID with `parity` (1 to 3) parity and `data` data devices per redundancy group
+    /// and `spares` distributed spares
+    pub fn draid(
+        parity: u64,
+        data: u64,
+        spares: u64,
+        devices: impl IntoIterator<Item = impl AsRef<str>>,
+    ) -> Self {
+        Self::Draid {
+            parity,
+            data,
+            spares,
+            devices: devices_from(devices),
+        }
+    }
+
+    fn raidz(parity: u64, devices: impl IntoIterator<Item = impl AsRef<str>>) -> Self {
+        Self::Raidz {
+            parity,
+            devices: devices_from(devices),
+        }
+    }
+
+    fn to_nvlist(&self, ashift: Option<u64>) -> Result<nvpair::NvList> {
+        let nvl = match self {
+            Self::Device(device) => leaf(device, ashift)?,
+            Self::Mirror(devices) => {
+                if devices.len() < 2 {
+                    return Err(invalid_vdev("mirror needs at least 2 devices"));
+                }
+                interior(VDEV_TYPE_MIRROR, devices, ashift)?
+            }
+            Self::Raidz { parity, devices } => {
+                if !(1..=3).contains(parity) {
+                    return Err(invalid_vdev("raidz parity must be 1 to 3"));
+                }
+                if devices.len() as u64 <= *parity {
+                    return Err(invalid_vdev("raidz needs more devices than parity"));
+                }
+                let mut nvl = interior(VDEV_TYPE_RAIDZ, devices, ashift)?;
+                nvl.add_uint64(ZPOOL_CONFIG_NPARITY, *parity)?;
+                nvl
+            }
+            Self::Draid {
+                parity,
+                data,
+                spares,
+                devices,
+            } => {
+                if !(1..=3).contains(parity) {
+                    return Err(invalid_vdev("draid parity must be 1 to 3"));
+                }
+                let width = data + parity;
+                let children = (devices.len() as u64)
+                    .checked_sub(*spares)
+                    .filter(|children| *data > 0 && width <= *children)
+                    .ok_or_else(|| invalid_vdev("draid needs data + parity + spares devices"))?;
+                // Smallest number of groups that fill the children evenly
+                let groups = (1..=children)
+                    .find(|groups| groups * width % children == 0)
+                    .unwrap_or(children);
+                let mut nvl = interior(VDEV_TYPE_DRAID, devices, ashift)?;
+                nvl.add_uint64(ZPOOL_CONFIG_NPARITY, *parity)?;
+                nvl.add_uint64(ZPOOL_CONFIG_DRAID_NDATA, *data)?;
+                nvl.add_uint64(ZPOOL_CONFIG_DRAID_NSPARES, *spares)?;
+                nvl.add_uint64(ZPOOL_CONFIG_DRAID_NGROUPS, groups)?;
+                nvl
+            }
+        };
+        Ok(nvl)
+    }
+}
+
+/// Vdevs to create a pool with or to add to one
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct VdevSpec {
+    topology: Vec<(Vdev, Option<&'static str>)>,
+    l2cache: Vec<String>,
+    spares: Vec<String>,
+    ashift: Option<u64>,
+}
+
+impl VdevSpec {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Data vdev
+    pub fn vdev(mut self, vdev: Vdev) -> Self {
+        self.topology.push((vdev, None));
+        self
+    }
+
+    /// Separate intent log vdev
+    pub fn log(mut self, vdev: Vdev) -> Self {
+        self.topology.push((vdev, Some(VDEV_ALLOC_BIAS_LOG)));
+        self
+    }
+
+    /// Special allocation class vdev, for metadata and small blocks
+    pub fn special(mut self, vdev: Vdev) -> Self {
+        self.topology.push((vdev, Some(VDEV_ALLOC_BIAS_SPECIAL)));
+        self
+    }
+
+    /// L2ARC cache device
+    pub fn cache(mut self, device: impl AsRef<str>) -> Self {
+        self.l2cache.push(device.as_ref().to_string());
+        self
+    }
+
+    /// Hot spare device
+    pub fn spare(mut self, device: impl AsRef<str>) -> Self {
+        self.spares.push(device.as_ref().to_string());
+        self
+    }
+
+    /// Ashift of the new leaf vdevs
+    pub fn ashift(mut self, ashift: u64) -> Self {
+        self.ashift = Some(ashift);
+        self
+    }
+
+    fn is_empty(&self) -> bool {
+        self.topology.is_empty() && self.l2cache.is_empty() && self.spares.is_empty()
+    }
+
+    pub(super) fn nvroot(&self) -> Result<nvpair::NvList> {
+        if self.is_empty() {
+            return Err(invalid_vdev("no vdevs"));
+        }
+
+        let mut vdevs = Vec::new();
+        for (vdev, bias) in &self.topology {
+            let mut nvl = vdev.to_nvlist(self.ashift)?;
+            nvl.add_uint64(
+                ZPOOL_CONFIG_IS_LOG,
+                u64::from(*bias == Some(VDEV_ALLOC_BIAS_LOG)),
+            )?;
+            if let Some(bias) = bias {
+                nvl.add_string(ZPOOL_CONFIG_ALLOCATION_BIAS, bias)?;
+            }
+            vdevs.push(nvl);
+        }
+
+        let mut nvroot = nvpair::NvList::new();
+        nvroot.add_string(ZPOOL_CONFIG_TYPE, VDEV_TYPE_ROOT)?;
+        nvroot.add_nvlist_array(ZPOOL_CONFIG_CHILDREN, &vdevs)?;
+        if !self.l2cache.is_empty() {
+            let l2cache = leaves(&self.l2cache, None)?;
+            nvroot.add_nvlist_array(ZPOOL_CONFIG_L2CACHE, &l2cache)?;
+        }
+        if !self.spares.is_empty() {
+            let spares = leaves(&self.spares, None)?;
+            nvroot.add_nvlist_array(ZPOOL_CONFIG_SPARES, &spares)?;
+        }
+        Ok(nvroot)
+    }
+
+    /// Root of a single leaf `device`, to attach or replace a vdev with
+    pub(super) fn device_nvroot(device: &str) -> Result<nvpair::NvList> {
+        let mut nvroot = nvpair::NvList::new();
+        nvroot.add_string(ZPOOL_CONFIG_TYPE, VDEV_TYPE_ROOT)?;
+        nvroot.add_nvlist_array(ZPOOL_CONFIG_CHILDREN, &[leaf(device, None)?])?;
+        Ok(nvroot)
+    }
+}
+
+#[derive(Debug)]
+pub struct ZpoolBuilder {
+    vdevs: VdevSpec,
+    props: nvpair::NvList,
+    fsprops: zfs::Properties,
+}
+
+impl ZpoolBuilder {
+    pub fn new() -> Self {
+        Self {
+            vdevs: VdevSpec::new(),
+            props: nvpair::NvList::new(),
+            fsprops: zfs::Properties::new(),
+        }
+    }
+
+    /// Create the pool and mount its root filesystem
+    pub fn create(self, name: impl AsRef<str>) -> Result<Zpool> {
+        let name = name.as_ref();
+        if !self.vdevs.topology.iter().any(|(_, bias)| bias.is_none()) {
+            return Err(invalid_vdev("pool needs at least one data vdev"));
+        }
+        let nvroot = self.vdevs.nvroot()?;
+        let fsprops = self.fsprops.into_inner();
+        libzfs::zpool_create(name, &nvroot, &self.props, &fsprops)
+            .map_err(|error| pool_error(name, error))?;
+
+        let pool = Zpool::get(name)?;
+        pool.pool.mount_datasets()?;
+        Ok(pool)
+    }
+
+    /// Data vdev
+    pub fn vdev(mut self, vdev: Vdev) -> Self {
+        self.vdevs = self.vdevs.vdev(vdev);
+        self
+    }
+
+    /// Separate intent log vdev
+    pub fn log(mut self, vdev: Vdev) -> Self {
+        self.vdevs = self.vdevs.log(vdev);
+        self
+    }
+
+    /// Special allocation class vdev, for metadata and small blocks
+    pub fn special(mut self, vdev: Vdev) -> Self {
+        self.vdevs = self.vdevs.special(vdev);
+        self
+    }
+
+    /// L2ARC cache device
+    pub fn cache(mut self, device: impl AsRef<str>) -> Self {
+        self.vdevs = self.vdevs.cache(device);
+        self
+    }
+
+    /// Hot spare device
+    pub fn spare(mut self, device: impl AsRef<str>) -> Self {
+        self.vdevs = self.vdevs.spare(device);
+        self
+    }
+
+    /// All the vdevs at once
+    pub fn vdevs(mut self, vdevs: VdevSpec) -> Self {
+        self.vdevs = vdevs;
+        self
+    }
+
+    pub fn ashift(mut self, ashift: u64) -> Self {
+        self.vdevs = self.vdevs.ashift(ashift);
+        self.property("ashift", ashift.to_string())
+    }
+
+    pub fn altroot(self, altroot: impl AsRef<str>) -> Self {
+        self.property("altroot", altroot)
+    }
+
+    pub fn cachefile(self, cachefile: impl AsRef<str>) -> Self {
+        self.property("cachefile", cachefile)
+    }
+
+    pub fn autoexpand(self, value: impl Into<property::OnOff>) -> Self {
+        self.property("autoexpand", value.into().as_str())
+    }
+
+    pub fn autotrim(self, value: impl Into<property::OnOff>) -> Self {
+        self.property("autotrim", value.into().as_str())
+    }
+
+    pub fn failmode(self, value: impl Into<property::Failmode>) -> Self {
+        self.property("failmode", value.into().as_str())
+    }
+
+    /// Any pool property, including `feature@<name>`
+    pub fn property(mut self, name: impl AsRef<str>, value: impl AsRef<str>) -> Self {
+        self.props += (name.as_ref(), value.as_ref());
+        self
+    }
+
+    /// Mountpoint of the root filesystem
+    pub fn mountpoint(mut self, mountpoint: impl AsRef<str>) -> Self {
+        self.fsprops.mountpoint(mountpoint);
+        self
+    }
+
+    /// Properties of the root filesystem
+    pub fn filesystem_properties(mut self, props: zfs::Properties) -> Self {
+        self.fsprops = props;
+        self
+    }
+}
+
+impl Default for ZpoolBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+fn interior(r#type: &str, devices: &[String], ashift: Option<u64>) -> Result<nvpair::NvList> {
+    let children = leaves(devices, ashift)?;
+    let mut nvl = nvpair::NvList::new();
+    nvl.add_string(ZPOOL_CONFIG_TYPE, r#type)?;
+    nvl.add_nvlist_array(ZPOOL_CONFIG_CHILDREN, &children)?;
+    Ok(nvl)
+}
+
+fn leaves(devices: &[String], ashift: Option<u64>) -> Result<Vec<nvpair::NvList>> {
+    devices.iter().map(|device| leaf(device, ashift)).collect()
+}
+
+// Whole disks are used as they are, without being partitioned first
+fn leaf(device: &str, ashift: Option<u64>) -> Result<nvpair::NvList> {
+    let path = if device.starts_with('/') {
+        device.to_string()
+    } else {
+        format!("{DEV_DIR}{device}")
+    };
+
+    // Block devices are disks and regular files are files, as with `zpool create`
+    let file_type = fs::metadata(&path)
+        .map_err(|error| invalid_vdev(&format!("cannot open {path}: {error}")))?
+        .file_type();
+    let mut nvl = nvpair::NvList::new();
+    if file_type.is_block_device() {
+        nvl.add_string(ZPOOL_CONFIG_TYPE, VDEV_TYPE_DISK)?;
+        nvl.add_uint64(ZPOOL_CONFIG_WHOLE_DISK, 0)?;
+    } else if file_type.is_file() {
+        nvl.add_string(ZPOOL_CONFIG_TYPE, VDEV_TYPE_FILE)?;
+    } else {
+        return Err(invalid_vdev(&format!(
+            "{path} is neither a block device nor a regular file"
+        )));
+    }
+    nvl.add_string(ZPOOL_CONFIG_PATH, path)?;
+    if let Some(ashift) = ashift {
+        nvl.add_uint64(ZPOOL_CONFIG_ASHIFT, ashift)?;
+    }
+    Ok(nvl)
+}
+
+fn devices_from(devices: impl IntoIterator<Item = impl AsRef<str>>) -> Vec<String> {
+    devices
+        .into_iter()
+        .map(|device| device.as_ref().to_string())
+        .collect()
+}
+
+fn invalid_vdev(reason: &str) -> DatasetError {
+    DatasetError::InvalidVdev(reason.to_string())
+}
@@ -1,4 +1,15 @@
 pub use allocated::Allocated;
+pub use altroot::Altroot;
+pub use ashift::Ashift;
+pub use bootfs::Bootfs;
+pub use cachefile::Cachefile;
+pub use expandsize::Expandsize;
+pub use failmode::Failmode;
+pub use feature::FeatureState;
+pub use health::Health;
+pub use onoff::OnOff;
+pub use version::Version;
+pub use yesno::YesNo;
 
 use super::InvalidProperty;
 
@@ -9,6 +20,7 @@ mod bootfs;
 mod cachefile;
 mod expandsize;
 mod failmode;
+mod feature;
 mod health;
 mod onoff;
 mod version;
@@ -4,11 +4,12 @@ use std::str::FromStr;
 
 use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
 pub struct Ashift(u64);
 
+// 0 lets ZFS detect the sector size of the vdevs
 fn check_range(shift: u64) -> Result<u64, super::InvalidProperty> {
-    if (9..=16).contains(&shift) {
+    if shift == 0 || (9..=16).contains(&shift) {
         Ok(shift)
     } else {
         Err(super::InvalidProperty::invalid_value(shift))
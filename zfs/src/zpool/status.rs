@@ -0,0 +1,687 @@
+use super::*;
+
+use libzfs::zpool_errata_t::*;
+use libzfs::zpool_status_t::*;
+use property::Health;
+
+const ZPOOL_CONFIG_ID: &str = "id";
+const ZPOOL_CONFIG_ERRCOUNT: &str = "error_count";
+
+const VDEV_TYPE_ROOT: &str = "root";
+const VDEV_TYPE_HOLE: &str = "hole";
+const VDEV_TYPE_INDIRECT: &str = "indirect";
+
+const VDEV_ALLOC_BIAS_DEDUP: &str = "dedup";
+
+// vdev_stat_t
+const VS_STATE: usize = 1;
+const VS_AUX: usize = 2;
+const VS_READ_ERRORS: usize = 20;
+const VS_WRITE_ERRORS: usize = 21;
+const VS_CHECKSUM_ERRORS: usize = 22;
+
+// vdev_state_t
+const VDEV_STATE_CLOSED: u64 = 1;
+const VDEV_STATE_OFFLINE: u64 = 2;
+const VDEV_STATE_REMOVED: u64 = 3;
+const VDEV_STATE_CANT_OPEN: u64 = 4;
+const VDEV_STATE_FAULTED: u64 = 5;
+const VDEV_STATE_DEGRADED: u64 = 6;
+const VDEV_STATE_HEALTHY: u64 = 7;
+
+// vdev_aux_t
+const VDEV_AUX_CORRUPT_DATA: u64 = 2;
+const VDEV_AUX_BAD_LOG: u64 = 13;
+
+// pool_scan_stat_t
+const PSS_FUNC: usize = 0;
+const PSS_STATE: usize = 1;
+const PSS_START_TIME: usize = 2;
+const PSS_END_TIME: usize = 3;
+const PSS_TO_EXAMINE: usize = 4;
+const PSS_EXAMINED: usize = 5;
+const PSS_PROCESSED: usize = 7;
+const PSS_ERRORS: usize = 8;
+const PSS_PASS_START: usize = 10;
+const PSS_PASS_SCRUB_PAUSE: usize = 11;
+const PSS_PASS_SCRUB_SPENT_PAUSED: usize = 12;
+const PSS_PASS_ISSUED: usize = 13;
+const PSS_ISSUED: usize = 14;
+
+// pool_scan_func_t
+const POOL_SCAN_SCRUB: u64 = 1;
+const POOL_SCAN_RESILVER: u64 = 2;
+
+// dsl_scan_state_t
+const DSS_SCANNING: u64 = 1;
+const DSS_FINISHED: u64 = 2;
+const DSS_CANCELED: u64 = 3;
+
+/// What `zpool status` reports about a pool
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct PoolStatus {
+    name: String,
+    guid: u64,
+    health: Health,
+    status: Option<String>,
+    action: Option<String>,
+    msgid: Option<String>,
+    scan: Option<ScanStatus>,
+    data_errors: u64,
+    vdevs: VdevStatus,
+    logs: Vec<VdevStatus>,
+    special: Vec<VdevStatus>,
+    dedup: Vec<VdevStatus>,
+    cache: Vec<VdevStatus>,
+    spares: Vec<VdevStatus>,
+}
+
+impl PoolStatus {
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn guid(&self) -> u64 {
+        self.guid
+    }
+
+    pub fn health(&self) -> Health {
+        self.health
+    }
+
+    /// What is wrong with the pool, `None` if nothing is
+    pub fn status(&self) -> Option<&str> {
+        self.status.as_deref()
+    }
+
+    /// What to do about it
+    pub fn action(&self) -> Option<&str> {
+        self.action.as_deref()
+    }
+
+    /// Id of the matching message in the ZFS message catalog (ZFS-8000-xx)
+    pub fn msgid(&self) -> Option<&str> {
+        self.msgid.as_deref()
+    }
+
+    /// Last or current scrub or resilver, `None` if there has been none
+    pub fn scan(&self) -> Option<ScanStatus> {
+        self.scan
+    }
+
+    /// Number of persistent data errors
+    pub fn data_errors(&self) -> u64 {
+        self.data_errors
+    }
+
+    /// Root of the data vdev tree
+    pub fn vdevs(&self) -> &VdevStatus {
+        &self.vdevs
+    }
+
+    pub fn logs(&self) -> &[VdevStatus] {
+        &self.logs
+    }
+
+    pub fn special(&self) -> &[VdevStatus] {
+        &self.special
+    }
+
+    pub fn dedup(&self) -> &[VdevStatus] {
+        &self.dedup
+    }
+
+    pub fn cache(&self) -> &[VdevStatus] {
+        &self.cache
+    }
+
+    pub fn spares(&self) -> &[VdevStatus] {
+        &self.spares
+    }
+
+    pub(super) fn from_pool(pool: &Zpool) -> Result<Self> {
+        let config = pool.pool.config().ok_or_else(DatasetError::missing_value)?;
+        let name = pool.name();
+        let nvroot = config
+            .lookup_nvpair(ZPOOL_CONFIG_VDEV_TREE)?
+            .ok_or_else(DatasetError::missing_value)?;
+        let nvroot = nvroot.nvlist();
+
+        let (status, msgid, errata) = pool.pool.status();
+        let explanation = explain(status, errata);
+
+        let mut vdevs = VdevStatus::from_config(&nvroot, &name);
+        let mut logs = Vec::new();
+        let mut special = Vec::new();
+        let mut dedup = Vec::new();
+        for vdev in std::mem::take(&mut vdevs.children) {
+            if vdev.log {
+                logs.push(vdev);
+            } else if vdev.alloc_bias.as_deref() == Some(VDEV_ALLOC_BIAS_SPECIAL) {
+                special.push(vdev);
+            } else if vdev.alloc_bias.as_deref() == Some(VDEV_ALLOC_BIAS_DEDUP) {
+                dedup.push(vdev);
+            } else {
+                vdevs.children.push(vdev);
+            }
+        }
+
+        Ok(Self {
+            guid: pool.guid(),
+            health: pool.health()?,
+            status: explanation.map(|(status, _)| status.to_string()),
+            action: explanation.map(|(_, action)| action.to_string()),
+            msgid,
+            scan: pool.scan_stats(),
+            data_errors: lookup_uint64(&config, ZPOOL_CONFIG_ERRCOUNT).unwrap_or_default(),
+            cache: VdevStatus::from_children(&nvroot, ZPOOL_CONFIG_L2CACHE, &name),
+            spares: VdevStatus::from_children(&nvroot, ZPOOL_CONFIG_SPARES, &name),
+            name,
+            vdevs,
+            logs,
+            special,
+            dedup,
+        })
+    }
+}
+
+/// Vdev as reported by `zpool status`, with its children
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct VdevStatus {
+    name: String,
+    guid: u64,
+    vdev_type: String,
+    health: Health,
+    read_errors: u64,
+    write_errors: u64,
+    checksum_errors: u64,
+    #[serde(skip)]
+    log: bool,
+    #[serde(skip)]
+    alloc_bias: Option<String>,
+    children: Vec<VdevStatus>,
+}
+
+impl VdevStatus {
+    /// Path of a leaf vdev, `<type>-<id>` of an interior one and pool name of the root
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn guid(&self) -> u64 {
+        self.guid
+    }
+
+    /// `disk`, `file`, `mirror`, `raidz`, ...
+    pub fn vdev_type(&self) -> &str {
+        &self.vdev_type
+    }
+
+    pub fn health(&self) -> Health {
+        self.health
+    }
+
+    pub fn read_errors(&self) -> u64 {
+        self.read_errors
+    }
+
+    pub fn write_errors(&self) -> u64 {
+        self.write_errors
+    }
+
+    pub fn checksum_errors(&self) -> u64 {
+        self.checksum_errors
+    }
+
+    pub fn children(&self) -> &[Self] {
+        &self.children
+    }
+
+    /// This vdev and all of its descendants, depth first
+    pub fn iter(&self) -> impl Iterator<Item = &Self> {
+        let mut stack = vec![self];
+        std::iter::from_fn(move || {
+            let vdev = stack.pop()?;
+            stack.extend(vdev.children.iter().rev());
+            Some(vdev)
+        })
+    }
+
+    fn from_config<T>(vdev: &nvpair::NvListRef<'_, T>, pool: &str) -> Self {
+        let stats = lookup_uint64_array(vdev, ZPOOL_CONFIG_VDEV_STATS).unwrap_or_default();
+        let stat = |index: usize| stats.get(index).copied().unwrap_or_default();
+        let vdev_type = lookup_string(vdev, ZPOOL_CONFIG_TYPE).unwrap_or_default();
+        let children = Self::from_children(vdev, ZPOOL_CONFIG_CHILDREN, pool);
+
+        Self {
+            name: vdev_name(vdev, &vdev_type, children.len(), pool),
+            guid: lookup_uint64(vdev, ZPOOL_CONFIG_GUID).unwrap_or_default(),
+            health: vdev_health(stat(VS_STATE), stat(VS_AUX)),
+            read_errors: stat(VS_READ_ERRORS),
+            write_errors: stat(VS_WRITE_ERRORS),
+            checksum_errors: stat(VS_CHECKSUM_ERRORS),
+            log: lookup_uint64(vdev, ZPOOL_CONFIG_IS_LOG) == Some(1),
+            alloc_bias: lookup_string(vdev, ZPOOL_CONFIG_ALLOCATION_BIAS),
+            vdev_type,
+            children,
+        }
+    }
+
+    // Holes and indirect vdevs left behind by removed vdevs are not shown
+    fn from_children<T>(vdev: &nvpair::NvListRef<'_, T>, name: &str, pool: &str) -> Vec<Self> {
+        match vdev.lookup_nvpair(name) {
+            Ok(Some(children)) => children
+                .nvlist_array()
+                .into_iter()
+                .filter(|child| {
+                    let vdev_type = lookup_string(child, ZPOOL_CONFIG_TYPE);
+                    !matches!(
+                        vdev_type.as_deref(),
+                        Some(VDEV_TYPE_HOLE | VDEV_TYPE_INDIRECT)
+                    )
+                })
+                .map(|child| Self::from_config(&child, pool))
+                .collect(),
+            _ => Vec::new(),
+        }
+    }
+}
+
+/// Action of `Zpool::scrub()`
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize)]
+pub enum ScrubAction {
+    /// Start a scrub, or resume a paused one
+    Start,
+    /// Pause the scrub, a later `Start` resumes it from there
+    Pause,
+    /// Stop the scrub
+    Stop,
+}
+
+impl ScrubAction {
+    pub(super) fn scan_args(self) -> (libzfs::pool_scan_func_t, libzfs::pool_scrub_cmd_t) {
+        use libzfs::pool_scan_func_t as func;
+        use libzfs::pool_scrub_cmd_t as cmd;
+
+        match self {
+            Self::Start => (func::POOL_SCAN_SCRUB, cmd::POOL_SCRUB_NORMAL),
+            Self::Pause => (func::POOL_SCAN_SCRUB, cmd::POOL_SCRUB_PAUSE),
+            Self::Stop => (func::POOL_SCAN_NONE, cmd::POOL_SCRUB_NORMAL),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize)]
+pub enum ScanFunction {
+    Scrub,
+    Resilver,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize)]
+pub enum ScanState {
+    Scanning,
+    Finished,
+    Canceled,
+}
+
+/// Progress of the last or current scrub or resilver
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize)]
+pub struct ScanStatus {
+    function: ScanFunction,
+    state: ScanState,
+    start_time: SystemTime,
+    end_time: Option<SystemTime>,
+    total: u64,
+    examined: u64,
+    issued: u64,
+    processed: u64,
+    errors: u64,
+    paused: Option<SystemTime>,
+    pass_start: u64,
+    pass_issued: u64,
+    pass_spent_paused: u64,
+}
+
+impl ScanStatus {
+    pub fn function(&self) -> ScanFunction {
+        self.function
+    }
+
+    pub fn state(&self) -> ScanState {
+        self.state
+    }
+
+    pub fn start_time(&self) -> SystemTime {
+        self.start_time
+    }
+
+    /// When the scan finished or was canceled
+    pub fn end_time(&self) -> Option<SystemTime> {
+        self.end_time
+    }
+
+    /// Total bytes to scan
+    pub fn total(&self) -> u64 {
+        self.total
+    }
+
+    /// Bytes located by the scanner
+    pub fn examined(&self) -> u64 {
+        self.examined
+    }
+
+    /// Bytes checked (scrub) or rebuilt (resilver)
+    pub fn issued(&self) -> u64 {
+        self.issued
+    }
+
+    /// Bytes repaired
+    pub fn processed(&self) -> u64 {
+        self.processed
+    }
+
+    pub fn errors(&self) -> u64 {
+        self.errors
+    }
+
+    /// When the scrub was paused, `None` unless it is paused
+    pub fn paused(&self) -> Option<SystemTime> {
+        self.paused
+    }
+
+    /// Bytes issued per second during the current pass, as of now
+    pub fn rate(&self) -> u64 {
+        // Time spent issuing during this pass, not counting pauses
+        let until = self.paused.unwrap_or_else(SystemTime::now);
+        let elapsed = until
+            .duration_since(SystemTime::UNIX_EPOCH)
+            .map_or(0, |since| since.as_secs())
+            .saturating_sub(self.pass_start)
+            .saturating_sub(self.pass_spent_paused)
+            .max(1);
+        self.pass_issued / elapsed
+    }
+
+    /// Estimated time left at the current rate, `None` unless scanning
+    pub fn eta(&self) -> Option<Duration> {
+        let left = self.total.saturating_sub(self.issued);
+        match (self.state, self.rate()) {
+            (ScanState::Scanning, rate) if rate > 0 => Some(Duration::from_secs(left / rate)),
+            _ => None,
+        }
+    }
+
+    /// Completion in percents
+    pub fn percent(&self) -> f64 {
+        if self.total == 0 {
+            0.0
+        } else {
+            self.issued as f64 * 100.0 / self.total as f64
+        }
+    }
+
+    // Same as print_scan_scrub_resilver_status()
+    pub(super) fn from_stats(stats: &[u64]) -> Option<Self> {
+        let stat = |index: usize| stats.get(index).copied().unwrap_or_default();
+        let function = match stat(PSS_FUNC) {
+            POOL_SCAN_SCRUB => ScanFunction::Scrub,
+            POOL_SCAN_RESILVER => ScanFunction::Resilver,
+            _ => return None,
+        };
+        let state = match stat(PSS_STATE) {
+            DSS_SCANNING => ScanState::Scanning,
+            DSS_FINISHED => ScanState::Finished,
+            DSS_CANCELED => ScanState::Canceled,
+            _ => return None,
+        };
+        let end_time = match (state, stat(PSS_END_TIME)) {
+            (ScanState::Scanning, _) | (_, 0) => None,
+            (_, secs) => Some(SystemTime::UNIX_EPOCH + Duration::from_secs(secs)),
+        };
+        let paused = match stat(PSS_PASS_SCRUB_PAUSE) {
+            0 => None,
+            secs => Some(SystemTime::UNIX_EPOCH + Duration::from_secs(secs)),
+        };
+
+        Some(Self {
+            function,
+            state,
+            start_time: SystemTime::UNIX_EPOCH + Duration::from_secs(stat(PSS_START_TIME)),
+            end_time,
+            total: stat(PSS_TO_EXAMINE),
+            examined: stat(PSS_EXAMINED),
+            issued: stat(PSS_ISSUED),
+            processed: stat(PSS_PROCESSED),
+            errors: stat(PSS_ERRORS),
+            paused,
+            pass_start: stat(PSS_PASS_START),
+            pass_issued: stat(PSS_PASS_ISSUED),
+            pass_spent_paused: stat(PSS_PASS_SCRUB_SPENT_PAUSED),
+        })
+    }
+}
+
+// Same as zpool_state_to_name()
+pub(super) fn vdev_health(state: u64, aux: u64) -> Health {
+    match state {
+        VDEV_STATE_CLOSED | VDEV_STATE_OFFLINE => Health::Offline,
+        VDEV_STATE_REMOVED => Health::Removed,
+        VDEV_STATE_CANT_OPEN if aux == VDEV_AUX_CORRUPT_DATA || aux == VDEV_AUX_BAD_LOG => {
+            Health::Faulted
+        }
+        VDEV_STATE_FAULTED => Health::Faulted,
+        VDEV_STATE_DEGRADED => Health::Degraded,
+        VDEV_STATE_CANT_OPEN => Health::Unavail,
+        VDEV_STATE_HEALTHY => Health::Online,
+        _ => Health::Unknown,
+    }
+}
+
+fn vdev_name<T>(
+    vdev: &nvpair::NvListRef<'_, T>,
+    vdev_type: &str,
+    children: usize,
+    pool: &str,
+) -> String {
+    if vdev_type == VDEV_TYPE_ROOT {
+        return pool.to_string();
+    }
+    if let Some(path) = lookup_string(vdev, ZPOOL_CONFIG_PATH) {
+        return path;
+    }
+
+    let id = lookup_uint64(vdev, ZPOOL_CONFIG_ID).unwrap_or_default();
+    let nparity = lookup_uint64(vdev, ZPOOL_CONFIG_NPARITY).unwrap_or_default();
+    match vdev_type {
+        VDEV_TYPE_RAIDZ => format!("{vdev_type}{nparity}-{id}"),
+        VDEV_TYPE_DRAID => {
+            let ndata = lookup_uint64(vdev, ZPOOL_CONFIG_DRAID_NDATA).unwrap_or_default();
+            let nspares = lookup_uint64(vdev, ZPOOL_CONFIG_DRAID_NSPARES).unwrap_or_default();
+            format!("{vdev_type}{nparity}:{ndata}d:{children}c:{nspares}s-{id}")
+        }
+        _ => format!("{vdev_type}-{id}"),
+    }
+}
+
+fn lookup_uint64<T>(nvl: &nvpair::NvListRef<'_, T>, name: &str) -> Option<u64> {
+    nvl.lookup_nvpair(name).ok()?.map(|nvp| nvp.uint64())
+}
+
+fn lookup_uint64_array<T>(nvl: &nvpair::NvListRef<'_, T>, name: &str) -> Option<Vec<u64>> {
+    nvl.lookup_nvpair(name)
+        .ok()?
+        .map(|nvp| nvp.uint64_array().to_vec())
+}
+
+fn lookup_string<T>(nvl: &nvpair::NvListRef<'_, T>, name: &str) -> Option<String> {
+    nvl.lookup_nvpair(name)
+        .ok()?
+        .map(|nvp| nvp.string().into_owned())
+}
+
+// Status and action text as printed by `zpool status`
+fn explain(
+    status: libzfs::zpool_status_t,
+    errata: libzfs::zpool_errata_t,
+) -> Option<(&'static str, &'static str)> {
+    let explanation = match status {
+        ZPOOL_STATUS_OK => return None,
+        ZPOOL_STATUS_MISSING_DEV_R => (
+            "One or more devices could not be opened. Sufficient replicas exist \
+             for the pool to continue functioning in a degraded state.",
+            "Attach the missing device and online it using 'zpool online'.",
+        ),
+        ZPOOL_STATUS_MISSING_DEV_NR => (
+            "One or more devices could not be opened. There are insufficient \
+             replicas for the pool to continue functioning.",
+            "Attach the missing device and online it using 'zpool online'.",
+        ),
+        ZPOOL_STATUS_CORRUPT_LABEL_R => (
+            "One or more devices could not be used because the label is missing \
+             or invalid. Sufficient replicas exist for the pool to continue \
+             functioning in a degraded state.",
+            "Replace the device using 'zpool replace'.",
+        ),
+        ZPOOL_STATUS_CORRUPT_LABEL_NR => (
+            "One or more devices could not be used because the label is missing \
+             or invalid. There are insufficient replicas for the pool to continue \
+             functioning.",
+            "Destroy and re-create the pool from a backup source.",
+        ),
+        ZPOOL_STATUS_FAILING_DEV => (
+            "One or more devices has experienced an unrecoverable error. An attempt \
+             was made to correct the error. Applications are unaffected.",
+            "Determine if the device needs to be replaced, and clear the errors \
+             using 'zpool clear' or replace the device with 'zpool replace'.",
+        ),
+        ZPOOL_STATUS_OFFLINE_DEV => (
+            "One or more devices has been taken offline by the administrator. \
+             Sufficient replicas exist for the pool to continue functioning in a \
+             degraded state.",
+            "Online the device using 'zpool online' or replace the device with \
+             'zpool replace'.",
+        ),
+        ZPOOL_STATUS_REMOVED_DEV => (
+            "One or more devices has been removed by the administrator. Sufficient \
+             replicas exist for the pool to continue functioning in a degraded state.",
+            "Online the device using 'zpool online' or replace the device with \
+             'zpool replace'.",
+        ),
+        ZPOOL_STATUS_RESILVERING => (
+            "One or more devices is currently being resilvered. The pool will \
+             continue to function, possibly in a degraded state.",
+            "Wait for the resilver to complete.",
+        ),
+        ZPOOL_STATUS_CORRUPT_DATA => (
+            "One or more devices has experienced an error resulting in data \
+             corruption. Applications may be affected.",
+            "Restore the file in question if possible. Otherwise restore the entire \
+             pool from backup.",
+        ),
+        ZPOOL_STATUS_CORRUPT_POOL => (
+            "The pool metadata is corrupted and the pool cannot be opened.",
+            "Destroy and re-create the pool from a backup source.",
+        ),
+        ZPOOL_STATUS_VERSION_OLDER => (
+            "The pool is formatted using a legacy on-disk format. The pool can still \
+             be used, but some features are unavailable.",
+            "Upgrade the pool using 'zpool upgrade'. Once this is done, the pool will \
+             no longer be accessible on software that does not support feature flags.",
+        ),
+        ZPOOL_STATUS_VERSION_NEWER => (
+            "The pool has been upgraded to a newer, incompatible on-disk version. \
+             The pool cannot be accessed on this system.",
+            "Access the pool from a system running more recent software, or restore \
+             the pool from backup.",
+        ),
+        ZPOOL_STATUS_FEAT_DISABLED => (
+            "Some supported features are not enabled on the pool. The pool can still \
+             be used, but some features are unavailable.",
+            "Enable all features using 'zpool upgrade'. Once this is done, the pool \
+             may no longer be accessible by software that does not support the features.",
+        ),
+        ZPOOL_STATUS_UNSUP_FEAT_READ => (
+            "The pool cannot be accessed on this system because it uses feature(s) \
+             not supported on this system.",
+            "Access the pool from a system that supports the required feature(s), \
+             or restore the pool from backup.",
+        ),
+        ZPOOL_STATUS_UNSUP_FEAT_WRITE => (
+            "The pool can only be accessed in read-only mode on this system. It cannot \
+             be accessed in read-write mode because it uses feature(s) not supported \
+             on this system.",
+            "Import the pool with \"-o readonly=on\", access the pool from a system \
+             that supports the required feature(s), or restore the pool from backup.",
+        ),
+        ZPOOL_STATUS_FAULTED_DEV_R => (
+            "One or more devices are faulted in response to persistent errors. \
+             Sufficient replicas exist for the pool to continue functioning in a \
+             degraded state.",
+            "Replace the faulted device, or use 'zpool clear' to mark the device \
+             repaired.",
+        ),
+        ZPOOL_STATUS_FAULTED_DEV_NR => (
+            "One or more devices are faulted in response to persistent errors. There \
+             are insufficient replicas for the pool to continue functioning.",
+            "Destroy and re-create the pool from a backup source. Manually marking the \
+             device repaired using 'zpool clear' may allow some data to be recovered.",
+        ),
+        ZPOOL_STATUS_IO_FAILURE_MMP => (
+            "The pool is suspended because multihost writes failed or were delayed; \
+             another system could import the pool undetected.",
+            "Make sure the pool's devices are connected, then reboot your system and \
+             import the pool.",
+        ),
+        ZPOOL_STATUS_IO_FAILURE_WAIT | ZPOOL_STATUS_IO_FAILURE_CONTINUE => (
+            "One or more devices are faulted in response to IO failures.",
+            "Make sure the affected devices are connected, then run 'zpool clear'.",
+        ),
+        ZPOOL_STATUS_BAD_LOG => (
+            "An intent log record could not be read. Waiting for administrator \
+             intervention to fix the faulted pool.",
+            "Either restore the affected device(s) and run 'zpool online', or ignore \
+             the intent log records by running 'zpool clear'.",
+        ),
+        ZPOOL_STATUS_NON_NATIVE_ASHIFT => (
+            "One or more devices are configured to use a non-native block size. \
+             Expect reduced performance.",
+            "Replace affected devices with devices that support the configured block \
+             size, or migrate data to a properly configured pool.",
+        ),
+        ZPOOL_STATUS_HOSTID_MISMATCH => (
+            "Mismatch between pool hostid and system hostid on imported pool. This \
+             pool was previously imported into a system with a different hostid, and \
+             then was verbatim imported into this system.",
+            "Export this pool on all systems on which it is imported. Then import it \
+             to correct the mismatch.",
+        ),
+        ZPOOL_STATUS_ERRATA => match errata {
+            ZPOOL_ERRATA_ZOL_2094_SCRUB => (
+                "Existing scrub in progress is using an incompatible on-disk format.",
+                "To correct the issue run 'zpool scrub'.",
+            ),
+            ZPOOL_ERRATA_ZOL_6845_ENCRYPTION => (
+                "Existing encrypted datasets contain an on-disk incompatibility \
+                 which needs to be corrected.",
+                "To correct the issue backup existing encrypted datasets to new \
+                 encrypted datasets and destroy the old ones.",
+            ),
+            ZPOOL_ERRATA_ZOL_8308_ENCRYPTION => (
+                "Existing encrypted snapshots and bookmarks contain an on-disk \
+                 incompatibility. This may cause on-disk corruption if they are \
+                 used with 'zfs recv'.",
+                "To correct the issue, enable the bookmark_v2 feature. No additional \
+                 action is needed if there are no encrypted snapshots or bookmarks.",
+            ),
+            _ => (
+                "The pool is affected by a known errata.",
+                "See the message catalog entry for the errata.",
+            ),
+        },
+        _ => (
+            "The pool is in an unexpected state.",
+            "See the message catalog entry for the status.",
+        ),
+    };
+    Some(explanation)
+}
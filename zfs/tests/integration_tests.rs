@@ -108,6 +108,186 @@ fn set_properties_volume() {
     assert_eq!(property::Compression::On, volume.compression());
 }
 
+#[test]
+fn set_typed_properties_filesystem() -> anyhow::Result<()> {
+    let namespace = TestNamespace::unique();
+    let name = namespace.unique_name();
+    let mut filesystem = Zfs::filesystem()
+        .quota("1G".parse::<property::Size>()?)
+        .recordsize(64 * 1024)
+        .sync(property::SyncMode::Always)
+        .xattr(property::Xattr::Sa)
+        .acltype(property::AclType::Posix)
+        .logbias(property::LogBias::Throughput)
+        .primarycache(property::Cache::Metadata)
+        .copies(2)
+        .snapdir(property::SnapDir::Visible)
+        .redundant_metadata(property::RedundantMetadata::Most)
+        .casesensitivity(property::CaseSensitivity::Insensitive)
+        .normalization(property::Normalization::FormC)
+        .create(&name)?;
+    assert_eq!(property::Size::Bytes(1 << 30), filesystem.quota());
+    assert_eq!(64 * 1024, filesystem.recordsize());
+    assert_eq!(property::SyncMode::Always, filesystem.sync());
+    assert_eq!(property::Xattr::Sa, filesystem.xattr());
+    assert_eq!(property::AclType::Posix, filesystem.acltype());
+    assert_eq!(property::LogBias::Throughput, filesystem.logbias());
+    assert_eq!(property::Cache::Metadata, filesystem.primarycache());
+    assert_eq!(2, filesystem.copies());
+    assert_eq!(property::SnapDir::Visible, filesystem.snapdir());
+    assert_eq!(
+        property::RedundantMetadata::Most,
+        filesystem.redundant_metadata()
+    );
+    assert_eq!(
+        property::CaseSensitivity::Insensitive,
+        filesystem.casesensitivity()
+    );
+    assert_eq!(property::Normalization::FormC, filesystem.normalization());
+
+    filesystem
+        .set()
+        .quota(property::Size::None)
+        .refquota(512 * 1024 * 1024)
+        .reservation(property::Size::Bytes(1024 * 1024))
+        .sync(property::SyncMode::Standard)
+        .dedup(property::Dedup::On)
+        .dnodesize(property::DnodeSize::Auto)
+        .snapshot_limit(Some(10))
+        .commit()?;
+    assert_eq!(property::Size::None, filesystem.quota());
+    assert_eq!(
+        property::Size::Bytes(512 * 1024 * 1024),
+        filesystem.refquota()
+    );
+    assert_eq!(property::Size::Bytes(1024 * 1024), filesystem.reservation());
+    assert_eq!(property::SyncMode::Standard, filesystem.sync());
+    assert_eq!(property::Dedup::On, filesystem.dedup());
+    assert_eq!(property::DnodeSize::Auto, filesystem.dnodesize());
+    assert_eq!(Some(10), filesystem.snapshot_limit());
+    assert_eq!(None, filesystem.filesystem_limit());
+
+    assert_eq!(
+        "1.5K".parse::<property::Size>()?,
+        property::Size::Bytes(1536)
+    );
+    assert_eq!(
+        "posixacl".parse::<property::AclType>()?,
+        property::AclType::Posix
+    );
+    assert!("sometimes".parse::<property::SyncMode>().is_err());
+    Ok(())
+}
+
+#[test]
+fn property_source_and_inherit() -> anyhow::Result<()> {
+    let namespace = TestNamespace::unique();
+    let name = namespace.unique_name();
+    let _parent = Zfs::filesystem()
+        .compression(property::Compression::Lz4)
+        .create(&name)?;
+    let mut child = Zfs::filesystem().create(format!("{name}/child"))?;
+
+    let compression = child.property_with_source::<property::Compression>("compression")?;
+    assert_eq!(&property::Compression::Lz4, compression.value());
+    assert_eq!(
+        &property::Source::Inherited(name.clone()),
+        compression.source()
+    );
+
+    let atime = child.property_with_source::<property::OnOff>("atime")?;
+    assert_eq!(&property::Source::Default, atime.source());
+
+    child
+        .set()
+        .compression(property::Compression::Off)
+        .commit()?;
+    let compression = child.property_with_source::<property::Compression>("compression")?;
+    assert_eq!(&property::Compression::Off, compression.value());
+    assert!(compression.is_local());
+
+    child.inherit("compression", false)?;
+    let compression = child.property_with_source::<property::Compression>("compression")?;
+    assert_eq!(&property::Compression::Lz4, compression.value());
+    assert_eq!(&property::Source::Inherited(name), compression.source());
+
+    assert!(child
+        .property_with_source::<String>("nosuchproperty")
+        .is_err());
+    Ok(())
+}
+
+#[test]
+fn user_properties() -> anyhow::Result<()> {
+    let namespace = TestNamespace::unique();
+    let name = namespace.unique_name();
+    let mut parent = Zfs::filesystem()
+        .property("razor-test:tenant", "acme")
+        .create(&name)?;
+    let mut child = Zfs::filesystem().create(format!("{name}/child"))?;
+
+    let tenant = &parent.user_properties()["razor-test:tenant"];
+    assert_eq!("acme", tenant.value());
+    assert!(tenant.is_local());
+    let tenant = &child.user_properties()["razor-test:tenant"];
+    assert_eq!(&property::Source::Inherited(name.clone()), tenant.source());
+
+    child.set_user_property("razor-test:tenant", "other")?;
+    child.set_user_property("razor-test:retention", "7d")?;
+    let props = child.user_properties();
+    assert_eq!("other", props["razor-test:tenant"].value());
+    assert_eq!("7d", props["razor-test:retention"].value());
+
+    child.inherit_user_property("razor-test:tenant")?;
+    assert_eq!("acme", child.user_properties()["razor-test:tenant"].value());
+
+    parent.inherit_user_property("razor-test:tenant")?;
+    assert!(!parent.user_properties().contains_key("razor-test:tenant"));
+
+    assert!(child.set_user_property("notuser", "x").is_err());
+    assert!(child.set_user_property("Razor:Upper", "x").is_err());
+    assert!(child
+        .set_user_property(format!("razor:{}", "x".repeat(256)), "x")
+        .is_err());
+    assert!(child.inherit_user_property("compression").is_err());
+    Ok(())
+}
+
+#[test]
+fn userspace_quotas() -> anyhow::Result<()> {
+    let namespace = TestNamespace::unique();
+    let name = namespace.unique_name();
+    let mut filesystem = Zfs::filesystem().userquota("root", 1 << 30).create(&name)?;
+
+    let root = filesystem
+        .userspace(zfs::UserquotaProp::User)?
+        .find(|entry| entry.id() == 0)
+        .expect("root owns the filesystem");
+    assert_eq!(zfs::UserquotaProp::User, root.kind());
+    assert_eq!(property::Size::Bytes(1 << 30), root.quota());
+    assert!(root.used() > 0);
+
+    filesystem
+        .set()
+        .userquota(0, property::Size::None)
+        .groupquota(0, 1 << 20)
+        .groupobjquota(0, Some(1000))
+        .commit()?;
+    let root = filesystem
+        .userspace(zfs::UserquotaProp::User)?
+        .find(|entry| entry.id() == 0)
+        .expect("root owns the filesystem");
+    assert_eq!(property::Size::None, root.quota());
+
+    let root = filesystem
+        .userspace(zfs::UserquotaProp::Group)?
+        .find(|entry| entry.id() == 0)
+        .expect("root group owns the filesystem");
+    assert_eq!(property::Size::Bytes(1 << 20), root.quota());
+    assert_eq!(Some(1000), root.objquota());
+    Ok(())
+}
+
 #[test]
 fn create_dup_filesystem() {
     let namespace = TestNamespace::unique();
@@ -202,7 +382,7 @@ fn filesystem_snapshot() {
     let snapshots = Zfs::list_from(filesystem.name())
         .snapshots()
         .recursive(true)
-        .get_collection();
+        .iter();
 
     for snapshot in snapshots {
         dbg!(snapshot.name());
@@ -230,7 +410,7 @@ fn get_non_existent_filesystem() {
 
 #[test]
 fn list_filesystems() {
-    let datasets = Zfs::list().filesystems().recursive(true).get_collection();
+    let datasets = Zfs::list().filesystems().recursive(true).iter();
 
     for dataset in datasets {
         dbg!(dataset.name());
@@ -314,7 +494,7 @@ fn list_filesystems() {
 
 #[test]
 fn list_volumes() {
-    let datasets = Zfs::list().volumes().recursive(true).get_collection();
+    let datasets = Zfs::list().volumes().recursive(true).iter();
 
     for dataset in datasets {
         dbg!(dataset.name());
@@ -324,11 +504,7 @@ fn list_volumes() {
 
 #[test]
 fn list_all() {
-    let datasets = Zfs::list()
-        .filesystems()
-        .volumes()
-        .recursive(true)
-        .get_collection();
+    let datasets = Zfs::list().filesystems().volumes().recursive(true).iter();
 
     for dataset in datasets {
         dbg!(dataset.name());
@@ -337,13 +513,89 @@ fn list_all() {
 
 #[test]
 fn list_all_non_recursive() {
-    let datasets = Zfs::list().filesystems().volumes().get_collection();
+    let datasets = Zfs::list().filesystems().volumes().iter();
 
     for dataset in datasets {
         dbg!(dataset.name());
     }
 }
 
+#[test]
+fn iter_depth_first() -> anyhow::Result<()> {
+    let namespace = TestNamespace::unique();
+    let root = namespace.unique_name();
+    let filesystem = Zfs::filesystem().create(&root)?;
+    Zfs::filesystem().create(format!("{root}/b"))?;
+    Zfs::filesystem().create(format!("{root}/a"))?;
+    Zfs::filesystem().create(format!("{root}/a/c"))?;
+    Zfs::filesystem().create(format!("{root}/a-b"))?;
+    filesystem.snapshot("snap1")?;
+    filesystem.snapshot("snap2")?;
+
+    let names = |datasets: zfs::DatasetIter| {
+        datasets
+            .map(|dataset| dataset.name().into_owned())
+            .collect::<Vec<_>>()
+    };
+
+    let all = names(
+        Zfs::list_from(&root)
+            .filesystems()
+            .snapshots()
+            .recursive(true)
+            .sort(zfs::SortBy::Name)
+            .iter(),
+    );
+    // Sorted as a whole, as `zfs list -s name`, rather than depth first
+    let expected = [
+        root.clone(),
+        format!("{root}/a"),
+        format!("{root}/a-b"),
+        format!("{root}/a/c"),
+        format!("{root}/b"),
+        format!("{root}@snap1"),
+        format!("{root}@snap2"),
+    ];
+    assert_eq!(all, expected);
+
+    let created = names(
+        Zfs::list_from(&root)
+            .filesystems()
+            .snapshots()
+            .recursive(true)
+            .sort(zfs::SortBy::CreateTxg)
+            .iter(),
+    );
+    let expected = [
+        root.clone(),
+        format!("{root}/b"),
+        format!("{root}/a"),
+        format!("{root}/a/c"),
+        format!("{root}/a-b"),
+        format!("{root}@snap1"),
+        format!("{root}@snap2"),
+    ];
+    assert_eq!(created, expected);
+
+    let shallow = names(Zfs::list_from(&root).max_depth(1).iter());
+    assert_eq!(shallow.len(), 4);
+    assert!(!shallow.contains(&format!("{root}/a/c")));
+
+    let latest = names(
+        Zfs::list_from(&root)
+            .snapshots()
+            .max_depth(1)
+            .sort_descending(zfs::SortBy::CreateTxg)
+            .iter(),
+    );
+    assert_eq!(latest, [format!("{root}@snap2"), format!("{root}@snap1")]);
+
+    let globbed = names(Zfs::list_from(&root).recursive(true).glob("*/?/c").iter());
+    assert_eq!(globbed, [format!("{root}/a/c")]);
+
+    Ok(())
+}
+
 #[test]
 fn create_delete_volume() -> anyhow::Result<()> {
     let namespace = TestNamespace::unique();
@@ -391,3 +643,534 @@ fn delete_non_existent_volume() {
     // ));
     // assert_eq!(expected, res);
 }
+
+#[test]
+fn snapshot_hold_release() -> anyhow::Result<()> {
+    let namespace = TestNamespace::unique();
+    let name = namespace.unique_name();
+    let filesystem = Zfs::filesystem().create(&name)?;
+    filesystem.snapshot("held")?;
+    let snapshot = Zfs::get_snapshot(format!("{name}@held"))?;
+    snapshot.hold("razor-test")?;
+    assert!(snapshot.holds()?.contains_key("razor-test"));
+    snapshot.release("razor-test")?;
+    assert!(snapshot.holds()?.is_empty());
+    Ok(())
+}
+
+#[test]
+fn snapshot_set_destroy() -> anyhow::Result<()> {
+    let namespace = TestNamespace::unique();
+    let name = namespace.unique_name();
+    let filesystem = Zfs::filesystem().create(&name)?;
+    let snapshots: zfs::SnapshotSet = ["a", "b", "c"]
+        .into_iter()
+        .map(|snapshot| {
+            filesystem.snapshot(snapshot)?;
+            Ok(format!("{name}@{snapshot}"))
+        })
+        .collect::<anyhow::Result<_>>()?;
+    assert_eq!(snapshots.len(), 3);
+    snapshots.clone().destroy(false)?;
+    assert!(snapshots
+        .iter()
+        .all(|snapshot| !Zfs::dataset_exists(snapshot)));
+    Ok(())
+}
+
+#[test]
+fn snapshot_builder_user_property() -> anyhow::Result<()> {
+    let namespace = TestNamespace::unique();
+    let name = namespace.unique_name();
+    let other = namespace.unique_name();
+    Zfs::filesystem().create(&name)?;
+    Zfs::filesystem().create(&other)?;
+    let snapshot = Zfs::snapshot()
+        .property("razor-test:retain-until", "2038-01-19")
+        .dataset(&other)
+        .create(format!("{name}@retain"))?;
+    assert_eq!(snapshot.name(), format!("{name}@retain"));
+    assert!(Zfs::dataset_exists(format!("{other}@retain")));
+
+    let e = Zfs::snapshot()
+        .property("compression", "on")
+        .create(format!("{name}@native"))
+        .unwrap_err();
+    assert!(matches!(
+        e,
+        zfs::DatasetError::InvalidProperty(property::InvalidProperty::NotUserProperty(_))
+    ));
+    Ok(())
+}
+
+#[test]
+fn filesystem_bookmarks() -> anyhow::Result<()> {
+    let namespace = TestNamespace::unique();
+    let name = namespace.unique_name();
+    let filesystem = Zfs::filesystem().create(&name)?;
+    filesystem.snapshot("snap")?;
+    let snapshot = Zfs::get_snapshot(format!("{name}@snap"))?;
+    let bookmark = Zfs::create_bookmark(snapshot.name(), format!("{name}#mark"))?;
+    assert_eq!(bookmark.guid(), snapshot.guid());
+
+    let bookmarks = filesystem.bookmarks()?;
+    assert_eq!(bookmarks, [bookmark]);
+    assert!(bookmarks[0].redact_snaps().is_empty());
+
+    Zfs::destroy_bookmarks(bookmarks.iter().map(|bookmark| bookmark.name()))?;
+    assert!(filesystem.bookmarks()?.is_empty());
+    Ok(())
+}
+
+#[test]
+fn encrypted_filesystem_keys() -> anyhow::Result<()> {
+    let namespace = TestNamespace::unique();
+    let name = namespace.unique_name();
+    let filesystem = Zfs::filesystem()
+        .canmount(property::CanMount::Off)
+        .encryption(
+            property::Encryption::Aes256Gcm,
+            property::KeyFormat::Passphrase,
+            zfs::KeySource::prompt("razor-test-passphrase"),
+        )
+        .acltype(property::AclType::Posix)
+        .create(&name)?;
+    assert_eq!(filesystem.encryption(), property::Encryption::Aes256Gcm);
+    assert_eq!(filesystem.keyformat(), property::KeyFormat::Passphrase);
+    assert_eq!(filesystem.acltype(), property::AclType::Posix);
+    assert_eq!(filesystem.keystatus(), property::KeyStatus::Available);
+
+    filesystem.unload_key()?;
+    let filesystem = Zfs::get_filesystem(&name)?;
+    assert_eq!(filesystem.keystatus(), property::KeyStatus::Unavailable);
+    assert!(filesystem.load_key(b"wrong-passphrase").is_err());
+    filesystem.load_key(b"razor-test-passphrase")?;
+    let filesystem = Zfs::get_filesystem(&name)?;
+    assert_eq!(filesystem.keystatus(), property::KeyStatus::Available);
+
+    let key = [0x5a; 32];
+    filesystem.change_key(property::KeyFormat::Raw, zfs::KeySource::prompt(&key[..]))?;
+    filesystem.unload_key()?;
+    let filesystem = Zfs::get_filesystem(&name)?;
+    filesystem.load_key(&key)?;
+    assert_eq!(filesystem.keyformat(), property::KeyFormat::Raw);
+    Ok(())
+}
+
+#[test]
+fn send_receive_options() -> anyhow::Result<()> {
+    let namespace = TestNamespace::unique();
+    let source = namespace.unique_name();
+    let target = namespace.unique_name();
+    let filesystem = Zfs::filesystem().create(&source)?;
+    filesystem.snapshot("send")?;
+
+    let stream = std::env::temp_dir().join(format!("razor-test-{}", std::process::id()));
+    let file = std::fs::File::create(&stream)?;
+    let options = zfs::SendOptions::plain().compressed(true);
+    Zfs::send(format!("{source}@send"), None::<&str>, options, file)?;
+
+    let file = std::fs::File::open(&stream)?;
+    let options = zfs::ReceiveOptions::new().force(true);
+    Zfs::receive(format!("{target}@send"), None::<&str>, options, file)?;
+    std::fs::remove_file(&stream)?;
+
+    assert!(Zfs::dataset_exists(format!("{target}@send")));
+    Ok(())
+}
+
+#[test]
+fn resume_interrupted_receive() -> anyhow::Result<()> {
+    let namespace = TestNamespace::unique();
+    let source = namespace.unique_name();
+    let target = namespace.unique_name();
+    let filesystem = Zfs::filesystem().create(&source)?;
+    filesystem.snapshot("send")?;
+
+    let stream = std::env::temp_dir().join(format!("razor-test-resume-{}", std::process::id()));
+    let file = std::fs::File::create(&stream)?;
+    Zfs::send(
+        format!("{source}@send"),
+        None::<&str>,
+        zfs::SendOptions::new(),
+        file,
+    )?;
+
+    // Cut the stream short to interrupt the receive
+    let file = std::fs::OpenOptions::new().write(true).open(&stream)?;
+    file.set_len(file.metadata()?.len() / 2)?;
+
+    let file = std::fs::File::open(&stream)?;
+    let options = zfs::ReceiveOptions::new().resumable(true);
+    assert!(Zfs::receive(format!("{target}@send"), None::<&str>, options, file).is_err());
+
+    let token = Zfs::get_filesystem(&target)?
+        .receive_resume_token()?
+        .expect("resume token");
+    assert_eq!(token.toname(), format!("{source}@send"));
+    assert_eq!(token.fromguid(), None);
+
+    let file = std::fs::File::create(&stream)?;
+    Zfs::send_resume(&token, file)?;
+    let file = std::fs::File::open(&stream)?;
+    Zfs::receive(format!("{target}@send"), None::<&str>, options, file)?;
+    std::fs::remove_file(&stream)?;
+
+    assert!(Zfs::dataset_exists(format!("{target}@send")));
+    assert!(Zfs::get_filesystem(&target)?
+        .receive_resume_token()?
+        .is_none());
+    Ok(())
+}
+
+#[test]
+fn snapshot_send_size() -> anyhow::Result<()> {
+    let namespace = TestNamespace::unique();
+    let name = namespace.unique_name();
+    let filesystem = Zfs::filesystem().create(&name)?;
+    filesystem.snapshot("first")?;
+    filesystem.snapshot("second")?;
+
+    let snapshot = Zfs::get_snapshot(format!("{name}@second"))?;
+    let full = snapshot.send_size(None::<&str>)?;
+    let incremental = snapshot.send_size(Some(format!("{name}@first")))?;
+    assert!(full > 0);
+    assert!(incremental <= full);
+
+    let space = Zfs::snaprange_space(format!("{name}@first"), format!("{name}@second"))?;
+    assert!(space <= filesystem.used());
+    Ok(())
+}
+
+#[test]
+fn clone_promote_rollback_rename() -> anyhow::Result<()> {
+    let namespace = TestNamespace::unique();
+    let name = namespace.unique_name();
+    let clone = namespace.unique_name();
+    let renamed = namespace.unique_name();
+    let filesystem = Zfs::filesystem().create(&name)?;
+    filesystem.snapshot("first")?;
+    filesystem.snapshot("second")?;
+
+    let first = Zfs::get_snapshot(format!("{name}@first"))?;
+    let second = Zfs::get_snapshot(format!("{name}@second"))?;
+    assert!(matches!(
+        filesystem.rollback_to(&first),
+        Err(zfs::DatasetError::NotLatestSnapshot(_))
+    ));
+    filesystem.rollback_to(&second)?;
+    assert_eq!(filesystem.rollback()?, format!("{name}@second"));
+
+    let dataset = second.clone_to(&clone, property::Properties::new())?;
+    assert!(matches!(dataset, zfs::Dataset::Filesystem(_)));
+    assert_eq!(dataset.name(), clone);
+
+    Zfs::get_filesystem(&clone)?.promote()?;
+    assert!(Zfs::dataset_exists(format!("{clone}@first")));
+    assert!(!Zfs::dataset_exists(format!("{name}@first")));
+
+    Zfs::rename(&clone, &renamed)?;
+    assert!(Zfs::dataset_exists(&renamed));
+    assert!(!Zfs::dataset_exists(&clone));
+    Ok(())
+}
+
+#[test]
+fn redacted_send() -> anyhow::Result<()> {
+    let namespace = TestNamespace::unique();
+    let name = namespace.unique_name();
+    let clone = namespace.unique_name();
+    let filesystem = Zfs::filesystem().create(&name)?;
+    filesystem.snapshot("base")?;
+    let base = Zfs::get_snapshot(format!("{name}@base"))?;
+    base.clone_to(&clone, property::Properties::new())?;
+    Zfs::get_filesystem(&clone)?.snapshot("redact")?;
+
+    let bookmark = base.redact("redacted", [format!("{clone}@redact")])?;
+    assert_eq!(bookmark.name(), format!("{name}#redacted"));
+    assert_eq!(bookmark.redact_snaps().len(), 1);
+    assert!(bookmark.redact_complete());
+
+    let stream = std::env::temp_dir().join(format!("razor-test-redact-{}", std::process::id()));
+    let file = std::fs::File::create(&stream)?;
+    Zfs::send_redacted(
+        format!("{name}@base"),
+        None::<&str>,
+        bookmark.name(),
+        zfs::SendOptions::new(),
+        file,
+    )?;
+    assert!(std::fs::metadata(&stream)?.len() > 0);
+    std::fs::remove_file(&stream)?;
+    Ok(())
+}
+
+#[test]
+fn pool_checkpoint() -> anyhow::Result<()> {
+    // A checkpoint blocks device removal and other checkpoints pool wide,
+    // so keep it away from the shared test pool
+    let name = format!("razor-checkpoint-{}", std::process::id());
+    let (dir, devices) = file_devices(&name, &["a"])?;
+    let pool = zfs::Zpool::builder()
+        .vdev(zfs::Vdev::device(&devices[0]))
+        .mountpoint("none")
+        .create(&name)?;
+
+    let checkpoint = pool.checkpoint()?;
+    let status = checkpoint.status()?.expect("checkpoint status");
+    assert_eq!(status.state(), zfs::CheckpointState::Exists);
+    checkpoint.commit()?;
+
+    // Discarding completes asynchronously
+    if let Some(status) = zfs::Zpool::get(&name)?.checkpoint_status() {
+        assert_eq!(status.state(), zfs::CheckpointState::Discarding);
+    }
+
+    pool.destroy(false)?;
+    std::fs::remove_dir_all(&dir)?;
+    Ok(())
+}
+
+#[test]
+fn vdev_initialize_progress() -> anyhow::Result<()> {
+    // Initializing writes over all the free space of the vdev
+    let name = format!("razor-initialize-{}", std::process::id());
+    let (dir, devices) = file_devices(&name, &["a"])?;
+    let mut pool = zfs::Zpool::builder()
+        .vdev(zfs::Vdev::device(&devices[0]))
+        .mountpoint("none")
+        .create(&name)?;
+    let vdevs = pool.vdev_progress();
+    let vdev = vdevs.first().expect("pool vdev");
+
+    pool.initialize([vdev.name()], zfs::VdevAction::Start)?;
+    pool.refresh()?;
+    let progress = pool
+        .vdev_progress()
+        .into_iter()
+        .find(|progress| progress.guid() == vdev.guid())
+        .expect("vdev progress");
+    assert!(matches!(
+        progress.initialize().state(),
+        zfs::ProgressState::Active | zfs::ProgressState::Complete
+    ));
+
+    // Initializing a small vdev may already be complete, so the first cancel may fail
+    let _ = pool.initialize([vdev.name()], zfs::VdevAction::Cancel);
+    assert!(matches!(
+        pool.initialize([vdev.name()], zfs::VdevAction::Cancel),
+        Err(zfs::DatasetError::BatchError(error))
+            if error.errors.first().is_some_and(|(name, _)| name == vdev.name())
+    ));
+
+    assert_eq!(
+        pool.trim(["/nonexistent"], 0, false, zfs::VdevAction::Start),
+        Err(zfs::DatasetError::UnknownVdev("/nonexistent".to_string()))
+    );
+
+    pool.destroy(false)?;
+    std::fs::remove_dir_all(&dir)?;
+    Ok(())
+}
+
+#[cfg(feature = "wait")]
+#[test]
+fn wait_for_activities() -> anyhow::Result<()> {
+    let namespace = TestNamespace::unique();
+    let filesystem = Zfs::filesystem().create(namespace.unique_name())?;
+    filesystem.wait(zfs::zfs_wait_activity_t::ZFS_WAIT_DELETEQ)?;
+    filesystem.destroy()?;
+
+    let pool = zfs::Zpool::get(namespace.pool())?;
+    pool.wait(zfs::zpool_wait_activity_t::ZPOOL_WAIT_FREE)?;
+    Ok(())
+}
+
+#[test]
+fn pool_properties() -> anyhow::Result<()> {
+    let namespace = TestNamespace::unique();
+    let pool = zfs::Zpool::get(namespace.pool())?;
+    assert_eq!(pool.name(), namespace.pool());
+    assert_eq!(pool.health()?, zfs::zpool::property::Health::Online);
+    assert_eq!(*pool.allocated()? + pool.free(), pool.size());
+    assert!(pool.capacity() <= 100);
+    pool.ashift()?;
+    pool.failmode()?;
+    pool.autotrim()?;
+    pool.version()?;
+
+    assert!(zfs::Zpool::list()
+        .iter()
+        .any(|listed| listed.guid() == pool.guid()));
+    Ok(())
+}
+
+#[test]
+fn pool_status() -> anyhow::Result<()> {
+    let namespace = TestNamespace::unique();
+    let pool = zfs::Zpool::get(namespace.pool())?;
+    let status = pool.status()?;
+    assert_eq!(status.name(), namespace.pool());
+    assert_eq!(status.guid(), pool.guid());
+    assert_eq!(status.health(), zfs::zpool::property::Health::Online);
+    assert_eq!(status.vdevs().name(), namespace.pool());
+
+    let leaves = pool.vdev_progress();
+    for leaf in &leaves {
+        let vdev = status
+            .vdevs()
+            .iter()
+            .chain(status.logs().iter().flat_map(|vdev| vdev.iter()))
+            .find(|vdev| vdev.guid() == leaf.guid())
+            .expect("leaf vdev status");
+        assert_eq!(vdev.name(), leaf.name());
+        assert_eq!(vdev.health(), zfs::zpool::property::Health::Online);
+    }
+
+    serde_json::to_string(&status)?;
+    Ok(())
+}
+
+#[test]
+fn pool_scrub() -> anyhow::Result<()> {
+    let namespace = TestNamespace::unique();
+    let mut pool = zfs::Zpool::get(namespace.pool())?;
+    pool.scrub(zfs::ScrubAction::Start)?;
+    pool.refresh()?;
+    let stats = pool.scan_stats().expect("scan stats");
+    assert_eq!(stats.function(), zfs::ScanFunction::Scrub);
+    assert!(stats.issued() <= stats.total());
+
+    // Scrubbing a small pool may already be finished, so stopping may fail
+    if pool.scrub(zfs::ScrubAction::Stop).is_ok() {
+        pool.refresh()?;
+        let stats = pool.scan_stats().expect("scan stats");
+        assert_eq!(stats.state(), zfs::ScanState::Canceled);
+        assert_eq!(stats.eta(), None);
+    }
+    Ok(())
+}
+
+#[test]
+fn pool_set_get_properties() -> anyhow::Result<()> {
+    let namespace = TestNamespace::unique();
+    let mut pool = zfs::Zpool::get(namespace.pool())?;
+    let autotrim = pool.autotrim()?;
+
+    pool.set()
+        .autotrim(zfs::zpool::property::OnOff::On)
+        .comment("razor")
+        .commit()?;
+    assert_eq!(pool.get_property("autotrim")?, "on");
+    assert_eq!(pool.get_property("comment")?, "razor");
+
+    pool.set().autotrim(autotrim).comment("").commit()?;
+    assert_eq!(pool.autotrim()?, autotrim);
+
+    assert!(matches!(
+        pool.get_property("nosuchproperty"),
+        Err(zfs::DatasetError::InvalidProperty(_))
+    ));
+
+    let features = pool.features();
+    assert!(features.contains_key("async_destroy"));
+    for (name, state) in features {
+        assert_eq!(pool.feature(&name)?, state);
+        assert_eq!(
+            pool.get_property(format!("feature@{name}"))?,
+            state.to_string()
+        );
+    }
+
+    // Pools have user properties since OpenZFS 2.2
+    if pool.set().property("razor:test", "value").commit().is_ok() {
+        assert_eq!(pool.get_property("razor:test")?, "value");
+    }
+    Ok(())
+}
+
+// Sparse files backing a private pool, in a directory named after it
+fn file_devices(
+    name: &str,
+    devices: &[&str],
+) -> std::io::Result<(std::path::PathBuf, Vec<String>)> {
+    let dir = std::env::temp_dir().join(name);
+    std::fs::create_dir_all(&dir)?;
+    let devices = devices
+        .iter()
+        .map(|device| {
+            let path = dir.join(device);
+            std::fs::File::create(&path)?.set_len(128 << 20)?;
+            Ok(path.to_string_lossy().into_owned())
+        })
+        .collect::<std::io::Result<Vec<_>>>()?;
+    Ok((dir, devices))
+}
+
+#[test]
+fn pool_create_export_import_destroy() -> anyhow::Result<()> {
+    let name = format!("razor-test-{}", std::process::id());
+    let (dir, devices) = file_devices(&name, &["a", "b", "log"])?;
+
+    assert!(matches!(
+        zfs::Zpool::builder()
+            .vdev(zfs::Vdev::mirror(&devices[..1]))
+            .create(&name),
+        Err(zfs::DatasetError::InvalidVdev(_))
+    ));
+
+    let pool = zfs::Zpool::builder()
+        .vdev(zfs::Vdev::mirror(&devices[..2]))
+        .log(zfs::Vdev::device(&devices[2]))
+        .mountpoint("none")
+        .create(&name)?;
+    assert_eq!(pool.name(), name);
+    assert_eq!(pool.vdev_progress().len(), 3);
+
+    pool.export(false)?;
+    assert_eq!(
+        zfs::Zpool::get(&name).unwrap_err(),
+        zfs::DatasetError::PoolNotFound(name.clone())
+    );
+
+    let pool = zfs::Zpool::import(&name, &[dir.to_string_lossy()])?;
+    pool.destroy(false)?;
+    std::fs::remove_dir_all(&dir)?;
+    Ok(())
+}
+
+#[test]
+fn pool_vdev_lifecycle() -> anyhow::Result<()> {
+    let name = format!("razor-vdev-{}", std::process::id());
+    let (dir, devices) = file_devices(&name, &["a", "b", "c", "d"])?;
+
+    let pool = zfs::Zpool::builder()
+        .vdev(zfs::Vdev::device(&devices[0]))
+        .mountpoint("none")
+        .create(&name)?;
+
+    pool.attach(&devices[0], &devices[1])?;
+    pool.offline(&devices[1], true)?;
+    assert_eq!(
+        pool.online(&devices[1], false)?,
+        zfs::zpool::property::Health::Online
+    );
+    pool.clear(None)?;
+    pool.detach(&devices[1])?;
+    assert_eq!(
+        pool.online(&devices[0], true)?,
+        zfs::zpool::property::Health::Online
+    );
+    assert_eq!(
+        pool.detach("/nonexistent"),
+        Err(zfs::DatasetError::UnknownVdev("/nonexistent".to_string()))
+    );
+
+    pool.add(zfs::VdevSpec::new().log(zfs::Vdev::device(&devices[2])))?;
+    pool.remove(&devices[2])?;
+    pool.replace(&devices[0], &devices[3])?;
+
+    pool.destroy(false)?;
+    std::fs::remove_dir_all(&dir)?;
+    Ok(())
+}
@@ -269,4 +269,25 @@ impl ZfsRpc for service::ZfsRpcService {
         let input = request.into_inner();
         service::recv_process(input).await
     }
+
+    async fn estimate_send(
+        &self,
+        request: Request<proto::EstimateSendRequest>,
+    ) -> ZfsRpcResult<proto::EstimateSendResponse> {
+        request.into_inner().execute().await
+    }
+
+    async fn userspace(
+        &self,
+        request: Request<proto::UserspaceRequest>,
+    ) -> ZfsRpcResult<proto::UserspaceResponse> {
+        request.into_inner().execute().await
+    }
+
+    async fn set_space_quota(
+        &self,
+        request: Request<proto::SetSpaceQuotaRequest>,
+    ) -> ZfsRpcResult<proto::Empty> {
+        request.into_inner().execute().await
+    }
 }
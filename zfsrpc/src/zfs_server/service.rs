@@ -27,6 +27,7 @@ mod pipe;
 mod recv;
 mod send;
 mod snapshot;
+mod userspace;
 mod volume;
 
 const FILESYSTEM: &str = "filesystem";
@@ -48,8 +49,7 @@ pub(crate) fn list() -> Result<proto::Datasets, ZfsError> {
         .volumes()
         .filesystems()
         .recursive(true)
-        .get_collection()?
-        .into_iter()
+        .iter()
         .map(proto::Dataset::try_from)
         .collect::<anyhow::Result<_>>()
         .map_err(|e| DatasetError::NvListError(NvListError::Message(format!("{:?}", e))))
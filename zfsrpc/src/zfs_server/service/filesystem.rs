@@ -77,13 +77,13 @@ impl proto::Filesystem {
             .try_fold(builder, Self::add_property)?
             .create(name)?;
 
-        Ok(fs.into())
+        Self::try_from(fs)
     }
 
     pub(crate) fn get(name: String) -> Result<Self, ZfsError> {
         let fs = Zfs::get_filesystem(&name)?;
 
-        Ok(fs.into())
+        Self::try_from(fs)
     }
 
     pub(crate) async fn mount(name: String, mountpoint: String) -> Result<(), ZfsError> {
@@ -131,9 +131,11 @@ impl proto::Filesystem {
     }
 }
 
-impl From<zfs::Filesystem> for proto::Filesystem {
-    fn from(fs: zfs::Filesystem) -> Self {
-        Self {
+impl TryFrom<zfs::Filesystem> for proto::Filesystem {
+    type Error = ZfsError;
+
+    fn try_from(fs: zfs::Filesystem) -> Result<Self, Self::Error> {
+        Ok(Self {
             name: Some(fs.name().into()),
             available: Some(fs.available().into()),
             atime: Some(fs.atime().into()),
@@ -159,6 +161,10 @@ impl From<zfs::Filesystem> for proto::Filesystem {
             referenced: Some(fs.referenced().into()),
             logicalreferenced: Some(fs.logicalreferenced().into()),
             objsetid: Some(fs.objsetid().into()),
-        }
+            receive_resume_token: fs
+                .receive_resume_token()?
+                .map(|token| token.to_string())
+                .unwrap_or_default(),
+        })
     }
 }
@@ -16,6 +16,10 @@ pub async fn recv(mut input: tonic::Streaming<proto::SendSegment>) -> ZfsRpcResu
     };
 
     let snapname = segment.name;
+    let options = segment
+        .options
+        .map(zfs::ReceiveOptions::from)
+        .unwrap_or_default();
     let mut expected_sequence = segment.sequence + 1;
     trace!(sequence = segment.sequence, "Receiving message");
 
@@ -25,7 +29,8 @@ pub async fn recv(mut input: tonic::Streaming<proto::SendSegment>) -> ZfsRpcResu
         .await
         .map_err(join_to_status)??;
 
-    let receiver = task::spawn_blocking(|| zfs::Zfs::receive(snapname, origin, false, reader));
+    let receiver =
+        task::spawn_blocking(move || zfs::Zfs::receive(snapname, origin, options, reader));
     writer.write_all(&segment.buffer).await?;
 
     while let Some(segment) = input.message().await? {
@@ -62,11 +67,15 @@ pub async fn recv_process(
     };
 
     let snapname = segment.name;
+    let options = segment
+        .options
+        .map(zfs::ReceiveOptions::from)
+        .unwrap_or_default();
     let mut expected_sequence = segment.sequence + 1;
     trace!(sequence = segment.sequence, "Receiving message");
 
     let origin: Option<String> = None;
-    let mut receiver = Zfs::receive_cmd(snapname, origin, false).map_err(zfs_to_status)?;
+    let mut receiver = Zfs::receive_cmd(snapname, origin, options).map_err(zfs_to_status)?;
     let mut stdin = receiver
         .stdin
         .take()
@@ -101,3 +110,12 @@ pub async fn recv_process(
 
     Ok(response)
 }
+
+impl From<proto::ReceiveOptions> for zfs::ReceiveOptions {
+    fn from(options: proto::ReceiveOptions) -> Self {
+        Self::new()
+            .raw(options.raw)
+            .force(options.force)
+            .resumable(options.resumable)
+    }
+}
@@ -13,16 +13,32 @@ pub type SendStream = Pin<Box<dyn Stream<Item = Result<proto::SendSegment, tonic
 
 impl proto::SendRequest {
     pub async fn execute(self) -> ZfsRpcResult<SendStream> {
-        let Self { from, source } = self;
+        let Self {
+            from,
+            source,
+            options,
+            resume_token,
+        } = self;
         let from = if from.is_empty() { None } else { Some(from) };
-        let name = source.clone();
+        let resume_token = resume_token_from(resume_token)?;
+        let options = resume_token.as_ref().map_or_else(
+            || options.map_or_else(zfs::SendOptions::default, zfs::SendOptions::from),
+            zfs::ResumeToken::options,
+        );
+        let receive_options = Some(receive_options(options, resume_token.as_ref()));
+        let name = resume_token
+            .as_ref()
+            .map_or_else(|| source.clone(), |token| token.toname().to_string());
         let (reader, writer) = pipe()?;
         let fd = writer.as_raw_fd();
         let buf_size = task::spawn_blocking(move || max_pipe_size(fd))
             .await
             .map_err(join_to_status)??;
         let mut reader = BufReader::with_capacity(buf_size, reader);
-        let sender = task::spawn_blocking(|| zfs::Zfs::send(source, from, writer));
+        let sender = task::spawn_blocking(move || match resume_token {
+            Some(token) => zfs::Zfs::send_resume(&token, writer),
+            None => zfs::Zfs::send(source, from, options, writer),
+        });
 
         let send_stream = async_stream::try_stream! {
             let mut sequence = 0;
@@ -35,6 +51,7 @@ impl proto::SendRequest {
                             name: name.clone(),
                             sequence,
                             buffer,
+                            options: receive_options.clone(),
                         };
                     yield segment;
                 } else {
@@ -51,11 +68,28 @@ impl proto::SendRequest {
     }
 
     pub async fn execute_process(self) -> ZfsRpcResult<SendStream> {
-        let Self { from, source } = self;
+        let Self {
+            from,
+            source,
+            options,
+            resume_token,
+        } = self;
         let from = if from.is_empty() { None } else { Some(from) };
-        let name = source.clone();
+        let resume_token = resume_token_from(resume_token)?;
+        let options = resume_token.as_ref().map_or_else(
+            || options.map_or_else(zfs::SendOptions::default, zfs::SendOptions::from),
+            zfs::ResumeToken::options,
+        );
+        let receive_options = Some(receive_options(options, resume_token.as_ref()));
+        let name = resume_token
+            .as_ref()
+            .map_or_else(|| source.clone(), |token| token.toname().to_string());
 
-        let mut send = Zfs::send_cmd(source, from).map_err(zfs_to_status)?;
+        let mut send = match resume_token {
+            Some(token) => Zfs::send_resume_cmd(&token),
+            None => Zfs::send_cmd(source, from, options),
+        }
+        .map_err(zfs_to_status)?;
         let stdout = send
             .stdout
             .take()
@@ -72,6 +106,7 @@ impl proto::SendRequest {
                             name: name.clone(),
                             sequence,
                             buffer,
+                            options: receive_options.clone(),
                         };
                     yield segment;
                 } else {
@@ -92,3 +127,61 @@ impl proto::SendRequest {
         Ok(Response::new(Box::pin(send_stream)))
     }
 }
+
+impl proto::EstimateSendRequest {
+    pub async fn execute(self) -> ZfsRpcResult<proto::EstimateSendResponse> {
+        let Self {
+            from,
+            source,
+            options,
+        } = self;
+        let from = if from.is_empty() { None } else { Some(from) };
+        let options = options.map_or_else(zfs::SendOptions::default, zfs::SendOptions::from);
+        task::spawn_blocking(move || zfs::Zfs::send_space(source, from, options))
+            .await
+            .map_err(join_to_status)?
+            .map(|size| proto::EstimateSendResponse { size })
+            .map(Response::new)
+            .map_err(zfs_to_status)
+    }
+}
+
+impl From<proto::SendOptions> for zfs::SendOptions {
+    fn from(options: proto::SendOptions) -> Self {
+        Self::plain()
+            .embed_data(options.embed_data)
+            .large_block(options.large_block)
+            .compressed(options.compressed)
+            .raw(options.raw)
+            .saved(options.saved)
+    }
+}
+
+// The receiving side has to know whether the stream is raw
+impl From<zfs::SendOptions> for proto::ReceiveOptions {
+    fn from(options: zfs::SendOptions) -> Self {
+        Self {
+            raw: options.is_raw(),
+            ..Self::default()
+        }
+    }
+}
+
+// A resumed stream has to be received as resumable again
+fn receive_options(
+    options: zfs::SendOptions,
+    resume_token: Option<&zfs::ResumeToken>,
+) -> proto::ReceiveOptions {
+    proto::ReceiveOptions {
+        resumable: resume_token.is_some(),
+        ..options.into()
+    }
+}
+
+fn resume_token_from(token: String) -> Result<Option<zfs::ResumeToken>, tonic::Status> {
+    if token.is_empty() {
+        Ok(None)
+    } else {
+        token.parse().map(Some).map_err(zfs_to_status)
+    }
+}
@@ -0,0 +1,90 @@
+use super::*;
+
+use crate::zfsrpc_proto::SpaceKind;
+
+impl proto::UserspaceRequest {
+    pub(crate) async fn execute(self) -> ZfsRpcResult<proto::UserspaceResponse> {
+        let kind = zfs::UserquotaProp::try_from(self.kind())?;
+        task::spawn_blocking(move || {
+            zfs::Filesystem::get(self.filesystem)?
+                .userspace(kind)
+                .map(|entries| entries.map(proto::SpaceEntry::from).collect())
+        })
+        .await
+        .map_err(join_to_status)?
+        .map(|entries| proto::UserspaceResponse { entries })
+        .map(Response::new)
+        .map_err(zfs_to_status)
+    }
+}
+
+impl proto::SetSpaceQuotaRequest {
+    pub(crate) async fn execute(self) -> ZfsRpcResult<proto::Empty> {
+        let kind = zfs::UserquotaProp::try_from(self.kind())?;
+        let Self {
+            filesystem,
+            id,
+            objects,
+            quota,
+            ..
+        } = self;
+        task::spawn_blocking(move || {
+            let mut filesystem = zfs::Filesystem::get(filesystem)?;
+            let setter = filesystem.set();
+            let objquota = Some(quota).filter(|quota| *quota != 0);
+            let setter = match (kind, objects) {
+                (zfs::UserquotaProp::User, false) => setter.userquota(id, quota),
+                (zfs::UserquotaProp::Group, false) => setter.groupquota(id, quota),
+                (zfs::UserquotaProp::Project, false) => setter.projectquota(id, quota),
+                (zfs::UserquotaProp::User, true) => setter.userobjquota(id, objquota),
+                (zfs::UserquotaProp::Group, true) => setter.groupobjquota(id, objquota),
+                (zfs::UserquotaProp::Project, true) => setter.projectobjquota(id, objquota),
+            };
+            setter.commit()
+        })
+        .await
+        .map_err(join_to_status)?
+        .map(|()| proto::Empty {})
+        .map(Response::new)
+        .map_err(zfs_to_status)
+    }
+}
+
+impl TryFrom<SpaceKind> for zfs::UserquotaProp {
+    type Error = tonic::Status;
+
+    fn try_from(kind: SpaceKind) -> Result<Self, Self::Error> {
+        match kind {
+            SpaceKind::Unspecified => {
+                Err(tonic::Status::invalid_argument("space kind is required"))
+            }
+            SpaceKind::User => Ok(Self::User),
+            SpaceKind::Group => Ok(Self::Group),
+            SpaceKind::Project => Ok(Self::Project),
+        }
+    }
+}
+
+impl From<zfs::UserquotaProp> for SpaceKind {
+    fn from(kind: zfs::UserquotaProp) -> Self {
+        match kind {
+            zfs::UserquotaProp::User => Self::User,
+            zfs::UserquotaProp::Group => Self::Group,
+            zfs::UserquotaProp::Project => Self::Project,
+        }
+    }
+}
+
+impl From<zfs::SpaceEntry> for proto::SpaceEntry {
+    fn from(entry: zfs::SpaceEntry) -> Self {
+        Self {
+            kind: SpaceKind::from(entry.kind()) as i32,
+            id: entry.id(),
+            domain: entry.domain().unwrap_or_default().to_string(),
+            used: entry.used(),
+            quota: entry.quota().into(),
+            objused: entry.objused(),
+            objquota: entry.objquota().unwrap_or(0),
+        }
+    }
+}
@@ -9,9 +9,13 @@ pub use tonic_zfsrpc::CreateVolumeRequest;
 pub use tonic_zfsrpc::Dataset;
 pub use tonic_zfsrpc::Datasets;
 pub use tonic_zfsrpc::Empty;
+pub use tonic_zfsrpc::EstimateSendRequest;
+pub use tonic_zfsrpc::EstimateSendResponse;
 pub use tonic_zfsrpc::Filesystem;
 pub use tonic_zfsrpc::ListDatasetsRequest;
 pub use tonic_zfsrpc::MountFilesystemRequest;
+pub use tonic_zfsrpc::ReceiveOptions;
+pub use tonic_zfsrpc::SendOptions;
 pub use tonic_zfsrpc::SendRequest;
 pub use tonic_zfsrpc::SendSegment;
 pub use tonic_zfsrpc::Snapshot;
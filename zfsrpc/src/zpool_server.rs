@@ -1,3 +1,7 @@
+use std::time::SystemTime;
+
+use razor_zfs as zfs;
+use tokio::task;
 use tonic::{Code, Request, Response, Status};
 use tracing::debug;
 
@@ -8,7 +12,10 @@ use zpool_cmds as zpool;
 
 use super::zfsrpc_proto::tonic_zpoolrpc::zpool_rpc_server::ZpoolRpc;
 use super::zfsrpc_proto::tonic_zpoolrpc::{
-    CreateRequest, DestroyRequest, Empty, GetEbsPathRequest, GetEbsPathResponse,
+    method, property, AddRequest, AttachRequest, CreateRequest, DestroyRequest, Empty,
+    GetEbsPathRequest, GetEbsPathResponse, Method, OfflineRequest, OnlineRequest, ReplaceRequest,
+    ScanFunction, ScanState, ScanStats, ScanStatsRequest, ScanStatsResponse, ScrubAction,
+    ScrubRequest, VdevClass, VdevRequest,
 };
 
 #[derive(Debug, Default)]
@@ -20,15 +27,37 @@ impl ZpoolRpc for ZpoolRpcService {
         let request = request.into_inner();
         debug!(?request);
 
-        let properties = request
-            .properties
-            .into_iter()
-            .filter(|p| p.property.is_some())
-            .collect();
+        let CreateRequest {
+            name,
+            method,
+            disks,
+            properties,
+        } = request;
+        blocking(move || {
+            if zfs::Zpool::get(&name).is_ok() {
+                debug!("{} already exists", name);
+                return Ok(());
+            }
+
+            let dirs: [&str; 0] = [];
+            if zfs::Zpool::import(&name, &dirs).is_ok() {
+                debug!("{} was imported", name);
+                return Ok(());
+            }
 
-        zpool::create(&request.name, request.method, request.disks, properties)
-            .await
-            .map_err(|e| Status::new(Code::Internal, e.to_string()))?;
+            let mut builder = vdevs(method, disks)
+                .into_iter()
+                .fold(zfs::Zpool::builder(), zfs::ZpoolBuilder::vdev);
+            for property in properties.into_iter().filter_map(|p| p.property) {
+                builder = match property {
+                    property::Property::Ashift(ashift) => builder.ashift(ashift.into()),
+                    property::Property::Mountpoint(mountpoint) => builder.mountpoint(mountpoint),
+                    property::Property::Cachefile(cachefile) => builder.cachefile(cachefile),
+                };
+            }
+            builder.create(&name).map(drop)
+        })
+        .await?;
 
         Ok(Response::new(Empty {}))
     }
@@ -37,9 +66,7 @@ impl ZpoolRpc for ZpoolRpcService {
         let request = request.into_inner();
         debug!(?request);
 
-        zpool::destroy(&request.name)
-            .await
-            .map_err(|e| Status::new(Code::Internal, e.to_string()))?;
+        with_pool(request.name, |pool| pool.destroy(false)).await?;
 
         Ok(Response::new(Empty {}))
     }
@@ -56,4 +83,232 @@ impl ZpoolRpc for ZpoolRpcService {
 
         Ok(Response::new(GetEbsPathResponse { path }))
     }
+
+    async fn scrub(&self, request: Request<ScrubRequest>) -> Result<Response<Empty>, Status> {
+        let request = request.into_inner();
+        debug!(?request);
+
+        let action = match request.action() {
+            ScrubAction::Unspecified => {
+                return Err(Status::invalid_argument("scrub action is required"));
+            }
+            ScrubAction::Start => zfs::ScrubAction::Start,
+            ScrubAction::Pause => zfs::ScrubAction::Pause,
+            ScrubAction::Stop => zfs::ScrubAction::Stop,
+        };
+        with_pool(request.name, move |pool| pool.scrub(action)).await?;
+
+        Ok(Response::new(Empty {}))
+    }
+
+    async fn scan_stats(
+        &self,
+        request: Request<ScanStatsRequest>,
+    ) -> Result<Response<ScanStatsResponse>, Status> {
+        let request = request.into_inner();
+        debug!(?request);
+
+        let stats = with_pool(request.name, |pool| Ok(pool.scan_stats()))
+            .await?
+            .map(ScanStats::from);
+
+        Ok(Response::new(ScanStatsResponse { stats }))
+    }
+
+    async fn add(&self, request: Request<AddRequest>) -> Result<Response<Empty>, Status> {
+        let request = request.into_inner();
+        debug!(?request);
+
+        let name = request.name.clone();
+        let vdevs = request.vdevs();
+        with_pool(name, move |pool| pool.add(vdevs)).await?;
+
+        Ok(Response::new(Empty {}))
+    }
+
+    async fn attach(&self, request: Request<AttachRequest>) -> Result<Response<Empty>, Status> {
+        let request = request.into_inner();
+        debug!(?request);
+
+        let AttachRequest {
+            name,
+            existing,
+            device,
+        } = request;
+        with_pool(name, move |pool| pool.attach(existing, device)).await?;
+
+        Ok(Response::new(Empty {}))
+    }
+
+    async fn detach(&self, request: Request<VdevRequest>) -> Result<Response<Empty>, Status> {
+        let request = request.into_inner();
+        debug!(?request);
+
+        let VdevRequest { name, device } = request;
+        with_pool(name, move |pool| pool.detach(device)).await?;
+
+        Ok(Response::new(Empty {}))
+    }
+
+    async fn replace(&self, request: Request<ReplaceRequest>) -> Result<Response<Empty>, Status> {
+        let request = request.into_inner();
+        debug!(?request);
+
+        let ReplaceRequest {
+            name,
+            old_device,
+            new_device,
+        } = request;
+        with_pool(name, move |pool| pool.replace(old_device, new_device)).await?;
+
+        Ok(Response::new(Empty {}))
+    }
+
+    async fn online(&self, request: Request<OnlineRequest>) -> Result<Response<Empty>, Status> {
+        let request = request.into_inner();
+        debug!(?request);
+
+        let OnlineRequest {
+            name,
+            device,
+            expand,
+        } = request;
+        with_pool(name, move |pool| pool.online(device, expand)).await?;
+
+        Ok(Response::new(Empty {}))
+    }
+
+    async fn offline(&self, request: Request<OfflineRequest>) -> Result<Response<Empty>, Status> {
+        let request = request.into_inner();
+        debug!(?request);
+
+        let OfflineRequest {
+            name,
+            device,
+            temporary,
+        } = request;
+        with_pool(name, move |pool| pool.offline(device, temporary)).await?;
+
+        Ok(Response::new(Empty {}))
+    }
+
+    async fn remove(&self, request: Request<VdevRequest>) -> Result<Response<Empty>, Status> {
+        let request = request.into_inner();
+        debug!(?request);
+
+        let VdevRequest { name, device } = request;
+        with_pool(name, move |pool| pool.remove(device)).await?;
+
+        Ok(Response::new(Empty {}))
+    }
+
+    async fn clear(&self, request: Request<VdevRequest>) -> Result<Response<Empty>, Status> {
+        let request = request.into_inner();
+        debug!(?request);
+
+        let VdevRequest { name, device } = request;
+        with_pool(name, move |pool| {
+            let device = if device.is_empty() {
+                None
+            } else {
+                Some(device.as_str())
+            };
+            pool.clear(device)
+        })
+        .await?;
+
+        Ok(Response::new(Empty {}))
+    }
+}
+
+impl AddRequest {
+    fn vdevs(self) -> zfs::VdevSpec {
+        let class = self.class();
+        let Self { method, disks, .. } = self;
+        let spec = zfs::VdevSpec::new();
+        match class {
+            VdevClass::Cache => return disks.iter().fold(spec, |spec, disk| spec.cache(disk)),
+            VdevClass::Spare => return disks.iter().fold(spec, |spec, disk| spec.spare(disk)),
+            VdevClass::Data | VdevClass::Log | VdevClass::Special => {}
+        }
+
+        let vdevs = vdevs(method, disks);
+        let add: fn(zfs::VdevSpec, zfs::Vdev) -> zfs::VdevSpec = match class {
+            VdevClass::Log => zfs::VdevSpec::log,
+            VdevClass::Special => zfs::VdevSpec::special,
+            _ => zfs::VdevSpec::vdev,
+        };
+        vdevs.into_iter().fold(spec, add)
+    }
+}
+
+// A single raidz or mirror vdev out of all the disks, or one vdev per disk
+fn vdevs(method: Option<Method>, disks: Vec<String>) -> Vec<zfs::Vdev> {
+    match method.and_then(|method| method.method) {
+        Some(method::Method::Raidz(_)) => vec![zfs::Vdev::raidz1(disks)],
+        Some(method::Method::Mirror(_)) => vec![zfs::Vdev::mirror(disks)],
+        None => disks.iter().map(zfs::Vdev::device).collect(),
+    }
+}
+
+async fn with_pool<T, F>(name: String, f: F) -> Result<T, Status>
+where
+    F: FnOnce(zfs::Zpool) -> zfs::Result<T> + Send + 'static,
+    T: Send + 'static,
+{
+    blocking(move || f(zfs::Zpool::get(name)?)).await
+}
+
+async fn blocking<T, F>(f: F) -> Result<T, Status>
+where
+    F: FnOnce() -> zfs::Result<T> + Send + 'static,
+    T: Send + 'static,
+{
+    task::spawn_blocking(f)
+        .await
+        .map_err(|e| Status::new(Code::Internal, e.to_string()))?
+        .map_err(pool_to_status)
+}
+
+fn pool_to_status(err: zfs::DatasetError) -> Status {
+    match err {
+        zfs::DatasetError::PoolNotFound(_) | zfs::DatasetError::UnknownVdev(_) => {
+            Status::new(Code::NotFound, err.to_string())
+        }
+        _ => Status::new(Code::Internal, err.to_string()),
+    }
+}
+
+impl From<zfs::ScanStatus> for ScanStats {
+    fn from(status: zfs::ScanStatus) -> Self {
+        let function = match status.function() {
+            zfs::ScanFunction::Scrub => ScanFunction::Scrub,
+            zfs::ScanFunction::Resilver => ScanFunction::Resilver,
+        };
+        let state = match status.state() {
+            zfs::ScanState::Scanning => ScanState::Scanning,
+            zfs::ScanState::Finished => ScanState::Finished,
+            zfs::ScanState::Canceled => ScanState::Canceled,
+        };
+
+        Self {
+            function: function as i32,
+            state: state as i32,
+            start_time: secs(Some(status.start_time())),
+            end_time: secs(status.end_time()),
+            paused: secs(status.paused()),
+            examined: status.examined(),
+            issued: status.issued(),
+            total: status.total(),
+            processed: status.processed(),
+            errors: status.errors(),
+            rate: status.rate(),
+            eta: status.eta().map_or(0, |eta| eta.as_secs()),
+        }
+    }
+}
+
+fn secs(time: Option<SystemTime>) -> u64 {
+    time.and_then(|time| time.duration_since(SystemTime::UNIX_EPOCH).ok())
+        .map_or(0, |since| since.as_secs())
 }